@@ -0,0 +1,190 @@
+//! Generates code from the files in the tree:
+//!
+//! - `years.rs`, included by `src/lib.rs`: a `yYYYY` module for every
+//!   `src/yYYYY/` directory, declaring each `dayNN.rs` in it.
+//! - `days.rs`, included by `src/days.rs`: the solver table built from them,
+//!   ordered by year and day.
+//! - `examples.rs`, included by `tests/examples.rs`: one test per part listed
+//!   in an `input/YYYY/dayN_short*.answers` file, run against the matching
+//!   `input/YYYY/dayN_short*.txt`. The input directory is the one `aoc.toml`
+//!   names, passed on to the tests as `AOC_INPUT_DIR`.
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn main() {
+    let root = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap());
+    let years = years(&root.join("src"));
+    fs::write(out.join("years.rs"), year_modules(&years)).unwrap();
+    fs::write(out.join("days.rs"), day_table(&years)).unwrap();
+    let input = input_dir(&root);
+    println!("cargo:rustc-env=AOC_INPUT_DIR={}", input.display());
+    fs::write(out.join("examples.rs"), examples(&input)).unwrap();
+}
+
+/// The input directory the runner would use: `input_dir` from the file
+/// `AOC_CONFIG` names or the nearest `aoc.toml`, relative to that file, and
+/// `input` next to the manifest without one.
+fn input_dir(root: &Path) -> PathBuf {
+    println!("cargo:rerun-if-env-changed=AOC_CONFIG");
+    let config = env::var_os("AOC_CONFIG").map(PathBuf::from).or_else(|| {
+        root.ancestors()
+            .map(|dir| dir.join("aoc.toml"))
+            .find(|path| path.is_file())
+    });
+    let Some(config) = config else {
+        return root.join("input");
+    };
+    println!("cargo:rerun-if-changed={}", config.display());
+
+    let table: toml::Table = fs::read_to_string(&config)
+        .unwrap()
+        .parse()
+        .unwrap_or_else(|e| panic!("{}: {}", config.display(), e));
+    let dir = table
+        .get("input_dir")
+        .and_then(|dir| dir.as_str())
+        .unwrap_or("input");
+    config.parent().unwrap_or(root).join(dir)
+}
+
+/// Returns the digits after `prefix` in `stem` and whatever follows them.
+fn split_number<'a>(stem: &'a str, prefix: &str) -> Option<(&'a str, &'a str)> {
+    let rest = stem.strip_prefix(prefix)?;
+    let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+    (digits > 0).then(|| rest.split_at(digits))
+}
+
+/// Entries of `dir` named `<prefix><digits><suffix>`, with the number, in
+/// numeric order.
+fn numbered(dir: &Path, prefix: &str, suffix: &str) -> Vec<(u32, PathBuf)> {
+    let mut found: Vec<(u32, PathBuf)> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .map(|entry| entry.unwrap().path())
+        .filter_map(|path| {
+            let name = path.file_name()?.to_str()?;
+            let (number, rest) = split_number(name, prefix)?;
+            if rest != suffix {
+                return None;
+            }
+            Some((number.parse().ok()?, path.clone()))
+        })
+        .collect();
+    found.sort();
+    found
+}
+
+/// Every `src/yYYYY/` directory with the `dayNN.rs` files in it. Day files
+/// must use a two-digit number, which the module and struct names share.
+fn years(src: &Path) -> Vec<(u32, Vec<(u32, PathBuf)>)> {
+    println!("cargo:rerun-if-changed={}", src.display());
+    numbered(src, "y", "")
+        .into_iter()
+        .filter(|(_, dir)| dir.is_dir())
+        .map(|(year, dir)| {
+            println!("cargo:rerun-if-changed={}", dir.display());
+            let days = numbered(&dir, "day", ".rs");
+            for (day, path) in &days {
+                let expected = format!("{}.rs", module(*day));
+                if path.file_name().unwrap() != expected.as_str() {
+                    panic!("{} should be named {}", path.display(), expected);
+                }
+            }
+            (year, days)
+        })
+        .collect()
+}
+
+/// The module of day `day`; its solver is the same name capitalised.
+fn module(day: u32) -> String {
+    format!("day{:02}", day)
+}
+
+fn year_modules(years: &[(u32, Vec<(u32, PathBuf)>)]) -> String {
+    let mut out = String::new();
+    for (year, days) in years {
+        writeln!(out, "pub mod y{} {{", year).unwrap();
+        for (day, path) in days {
+            writeln!(
+                out,
+                "    #[path = {:?}]\n    pub mod {};",
+                path.display().to_string(),
+                module(*day)
+            )
+            .unwrap();
+        }
+        out.push_str("}\n");
+    }
+    out
+}
+
+fn day_table(years: &[(u32, Vec<(u32, PathBuf)>)]) -> String {
+    let mut out = String::from("static DAYS: &[&dyn Solver] = &[\n");
+    for (year, days) in years {
+        for (day, _) in days {
+            writeln!(
+                out,
+                "    &crate::y{}::{}::Day{:02},",
+                year,
+                module(*day),
+                day
+            )
+            .unwrap();
+        }
+    }
+    out.push_str("];\n");
+    out
+}
+
+fn examples(input: &Path) -> String {
+    println!("cargo:rerun-if-changed={}", input.display());
+
+    let mut examples: Vec<(u32, u32, String, Vec<u8>)> = vec![];
+    for (year, dir) in numbered(input, "", "") {
+        println!("cargo:rerun-if-changed={}", dir.display());
+        for path in fs::read_dir(&dir).into_iter().flatten() {
+            let path = path.unwrap().path();
+            if path.extension().is_none_or(|e| e != "answers") {
+                continue;
+            }
+            let Some(stem) = path.file_stem().and_then(|s| s.to_str()) else {
+                continue;
+            };
+            let Some((day, suffix)) = split_number(stem, "day") else {
+                continue;
+            };
+            if !suffix.starts_with("_short") {
+                continue;
+            }
+            println!("cargo:rerun-if-changed={}", path.display());
+
+            let answers = fs::read_to_string(&path).unwrap();
+            let parts = (1..=2)
+                .filter(|part| {
+                    let key = format!("part{}:", part);
+                    answers.lines().any(|l| l.trim_start().starts_with(&key))
+                })
+                .collect();
+            examples.push((year, day.parse().unwrap(), stem.to_owned(), parts));
+        }
+    }
+    examples.sort();
+
+    let mut out = String::new();
+    for (year, day, name, parts) in &examples {
+        for part in parts {
+            let part_name = if *part == 1 { "One" } else { "Two" };
+            writeln!(
+                out,
+                "#[test]\nfn y{}_{}_part{}() {{\n    check_example({}, {}, {:?}, Part::{});\n}}\n",
+                year, name, part, year, day, name, part_name
+            )
+            .unwrap();
+        }
+    }
+    out
+}
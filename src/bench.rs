@@ -0,0 +1,165 @@
+use crate::cli::BenchArgs;
+use crate::etc::Part;
+use crate::history;
+use crate::runner::{guarded, Failure};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Summary statistics over the timed runs of one phase.
+pub struct Stats {
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub stddev: f64,
+    /// Samples outside Tukey's fences (1.5 × IQR beyond the quartiles).
+    pub outliers: usize,
+}
+
+impl Stats {
+    pub fn new(samples: &[Duration]) -> Self {
+        let mut ms: Vec<f64> = samples
+            .iter()
+            .map(|d| d.as_nanos() as f64 / 1_000_000.0)
+            .collect();
+        ms.sort_by(f64::total_cmp);
+
+        let n = ms.len() as f64;
+        let mean = ms.iter().sum::<f64>() / n;
+        let variance = ms.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+        let (q1, q3) = (quantile(&ms, 0.25), quantile(&ms, 0.75));
+        let iqr = q3 - q1;
+        let (low, high) = (q1 - 1.5 * iqr, q3 + 1.5 * iqr);
+
+        Self {
+            min: ms[0],
+            median: quantile(&ms, 0.5),
+            mean,
+            stddev: variance.sqrt(),
+            outliers: ms.iter().filter(|&&x| x < low || x > high).count(),
+        }
+    }
+}
+
+/// Linearly interpolated quantile of sorted, non-empty samples.
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let pos = q * (sorted.len() - 1) as f64;
+    let (lo, hi) = (pos.floor() as usize, pos.ceil() as usize);
+    sorted[lo] + (sorted[hi] - sorted[lo]) * (pos - lo as f64)
+}
+
+fn print_stats(label: &str, stats: &Stats) {
+    println!(
+        "  · {}: min {:.4} ms, median {:.4} ms, mean {:.4} ms ± {:.4} ms, {} outliers",
+        label, stats.min, stats.median, stats.mean, stats.stddev, stats.outliers
+    );
+}
+
+/// Runs each selected day `warmup + iterations` times and prints timing
+/// statistics for parsing and each part. Input is loaded once per day and
+/// excluded from the timings. A day that errors or panics is reported and
+/// skipped, and the medians of the others are appended to the bench history.
+/// Returns false if any day failed.
+pub fn bench(args: &BenchArgs) -> bool {
+    let mut failed = false;
+    let mut medians = vec![];
+
+    for task in &args.run.tasks {
+        let solver = task.solver;
+        let (year, day) = (solver.year(), solver.number());
+        println!(
+            "\n=== {} Day {:02}: {} ({} runs, {} warmup) ===",
+            year,
+            day,
+            solver.title(),
+            args.iterations,
+            args.warmup
+        );
+
+        let content = match args.run.input.load(year, day) {
+            Ok(content) => content,
+            Err(e) => {
+                println!("  · {}", Failure::from(e));
+                failed = true;
+                continue;
+            }
+        };
+
+        let mut parse_samples = vec![];
+        let mut part_samples: Vec<(Part, Vec<Duration>)> =
+            task.parts.iter().map(|&p| (p, vec![])).collect();
+        let mut unsolved = vec![];
+        let result = guarded(|| {
+            (0..args.warmup + args.iterations).try_for_each(|i| {
+                let timed = i >= args.warmup;
+
+                let time = Instant::now();
+                let input = solver.parse(black_box(&content))?;
+                if timed {
+                    parse_samples.push(time.elapsed());
+                }
+
+                for (part, samples) in part_samples.iter_mut() {
+                    let time = Instant::now();
+                    let answer = black_box(solver.solve(*part, &*input)?);
+                    if !answer.is_solved() && !unsolved.contains(part) {
+                        unsolved.push(*part);
+                    }
+                    if timed {
+                        samples.push(time.elapsed());
+                    }
+                }
+                Ok(())
+            })
+        });
+
+        if let Err(e) = result {
+            println!("  · {}", e);
+            failed = true;
+            continue;
+        }
+
+        let stats = Stats::new(&parse_samples);
+        print_stats("Parse", &stats);
+        medians.push((year, day, "parse", (stats.median * 1_000_000.0) as u64));
+        for (part, samples) in &part_samples {
+            if unsolved.contains(part) {
+                println!("  · Part {}: unsolved", part);
+                continue;
+            }
+            let stats = Stats::new(samples);
+            print_stats(&format!("Part {}", part), &stats);
+            let phase = match part {
+                Part::One => "part1",
+                Part::Two => "part2",
+            };
+            medians.push((year, day, phase, (stats.median * 1_000_000.0) as u64));
+        }
+    }
+
+    if !medians.is_empty() {
+        if let Err(e) = history::record(&medians, args.baseline.as_deref()) {
+            eprintln!("Could not record bench history: {}", e);
+            failed = true;
+        }
+    }
+
+    !failed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples: Vec<Duration> = [1, 2, 3, 4, 100]
+            .iter()
+            .map(|&ms| Duration::from_millis(ms))
+            .collect();
+        let stats = Stats::new(&samples);
+        assert_eq!(stats.min, 1.0);
+        assert_eq!(stats.median, 3.0);
+        assert_eq!(stats.mean, 22.0);
+        assert_eq!(stats.outliers, 1);
+    }
+}
@@ -0,0 +1,536 @@
+use crate::config::Config;
+use crate::days;
+use crate::etc::{InputSource, Part, Solver};
+use serde::Deserialize;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// A day to run, and which of its parts.
+#[derive(Clone)]
+pub struct Task {
+    pub solver: &'static dyn Solver,
+    pub parts: Vec<Part>,
+}
+
+/// How run results are written to stdout.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+pub struct RunArgs {
+    pub tasks: Vec<Task>,
+    pub input: InputSource,
+    /// Store answers for parts that have none locked in yet.
+    pub lock: bool,
+    /// Give up on a day once it has run for this long.
+    pub timeout: Option<Duration>,
+    /// Worker threads to run days on; 1 runs them in order on the main thread.
+    pub jobs: usize,
+    pub format: Format,
+}
+
+pub struct BenchArgs {
+    pub run: RunArgs,
+    pub iterations: usize,
+    pub warmup: usize,
+    /// Also save this run as the named baseline.
+    pub baseline: Option<String>,
+}
+
+pub struct CompareArgs {
+    /// Compare against this saved baseline instead of the previous run.
+    pub baseline: Option<String>,
+    /// Slowdown, in percent, beyond which a phase counts as regressed.
+    pub threshold: f64,
+}
+
+pub enum Command {
+    List,
+    Run(RunArgs),
+    /// Run the selected days against every input profile of their year.
+    Profiles(RunArgs),
+    Bench(BenchArgs),
+    Compare(CompareArgs),
+    New {
+        year: u16,
+        day: u8,
+        title: Option<String>,
+    },
+}
+
+pub const USAGE: &str = "\
+Usage: advent_of_code_template list
+       advent_of_code_template [run] [days...] [options]
+       advent_of_code_template bench [days...] [options] [bench options]
+       advent_of_code_template compare [--baseline <name>] [--threshold <percent>]
+       advent_of_code_template new [year] <day> [title]
+
+Days are comma- or space-separated terms:
+  <year>          pick days from <year> from here on, e.g. `2023 1-3 2024 5`;
+                  a year with no days after it runs all of that year
+  <day>           run both parts of a day, e.g. `6`
+  <from>-<to>     run a range of days, e.g. `1-6`
+  all             run every registered day of the year
+  !<days>         leave out a day or range, e.g. `all,!6`
+  <days>:<part>   run a single part, e.g. `6:2` or `1-6:1`
+
+Options:
+  --implemented   only run days that have a solution; `all` of the year if no
+                  days are given
+  --part <n>      run only part <n> of days that don't name a part
+  --example       read input/YYYY/dayN_short.txt instead of input/YYYY/dayN.txt
+  --input <path>  read every selected day's input from <path>, or stdin for `-`
+  --profile <name>
+                  read input/YYYY/<name>/dayN.txt, checked against
+                  input/YYYY/<name>/dayN.answers
+  --profiles      run each selected day against every profile and print a
+                  matrix of answers and times, flagging panics and mismatches
+  --lock          store answers to answers/YYYY/dayNN.txt, or next to the
+                  --profile input, for parts without one
+  --timeout <s>   report a day as timed out after <s> seconds and move on
+  --jobs <n>      run days on <n> worker threads and print one table at the end
+  --format <f>    print results as `text` (default), `json` or `csv`
+  --checked       rebuild with overflow checks (`checked` profile) and rerun,
+                  so arithmetic overflow panics instead of wrapping
+
+Bench options:
+  --iterations <n>  timed runs per day (default 20)
+  --warmup <n>      untimed runs per day before timing starts (default 3)
+  --save-baseline <name>
+                    also save the medians as baseline <name>
+
+Every bench run appends its medians to .aoc/bench_history.csv. `compare`
+checks the latest run against the previous one, or a saved baseline, and
+flags phases that slowed down by more than --threshold percent (default 10).
+
+Defaults for --format, --timeout, --jobs, --iterations and --warmup, the input
+directory, the default year and the log filter can be set in aoc.toml, found
+in the current directory or one of its parents, or named by the AOC_CONFIG
+variable.";
+
+/// Parses the command-line arguments, excluding the program name. Options
+/// that aren't given fall back to `config`.
+pub fn parse(args: &[String], config: &Config) -> Result<Command, String> {
+    let (bench, args) = match args.first().map(String::as_str) {
+        Some("list") => return Ok(Command::List),
+        Some("compare") => return parse_compare(&args[1..]),
+        Some("new") => return parse_new(&args[1..], config),
+        Some("bench") => (true, &args[1..]),
+        Some("run") => (false, &args[1..]),
+        _ => (false, args),
+    };
+
+    let mut iterations = config.bench.iterations.unwrap_or(20).max(1);
+    let mut warmup = config.bench.warmup.unwrap_or(3);
+    let mut baseline = None;
+    let mut default_parts = Part::ALL.to_vec();
+    let mut input = InputSource::Puzzle;
+    let mut implemented_only = false;
+    let mut all_profiles = false;
+    let mut lock = false;
+    let mut timeout = config.run.timeout.map(Duration::from_secs_f64);
+    let mut jobs = config.run.jobs.unwrap_or(1).max(1);
+    let mut format = config.run.format.unwrap_or(Format::Text);
+    let mut year = days::default_year(config);
+    // A year with no days after it selects all of that year's days.
+    let mut bare_year = None;
+    let mut selected: Vec<(&'static dyn Solver, Option<Part>)> = vec![];
+    let mut excluded: Vec<&'static dyn Solver> = vec![];
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if arg == "--part" {
+            let part = args.next().ok_or("--part needs a value")?;
+            default_parts = vec![part.parse()?];
+        } else if bench && arg == "--iterations" {
+            iterations = parse_count(arg, args.next())?.max(1);
+        } else if bench && arg == "--warmup" {
+            warmup = parse_count(arg, args.next())?;
+        } else if bench && arg == "--save-baseline" {
+            baseline = Some(parse_name(arg, args.next())?);
+        } else if !bench && arg == "--lock" {
+            lock = true;
+        } else if !bench && arg == "--timeout" {
+            let secs = args.next().ok_or("--timeout needs a value")?;
+            timeout = Some(
+                secs.parse::<f64>()
+                    .ok()
+                    .filter(|s| s.is_finite() && *s > 0.0)
+                    .map(Duration::from_secs_f64)
+                    .ok_or_else(|| format!("Not a valid timeout: {}", secs))?,
+            );
+        } else if !bench && arg == "--jobs" {
+            jobs = parse_count(arg, args.next())?.max(1);
+        } else if !bench && arg == "--format" {
+            format = match args.next().ok_or("--format needs a value")?.as_str() {
+                "text" => Format::Text,
+                "json" => Format::Json,
+                "csv" => Format::Csv,
+                other => return Err(format!("Not a valid format: {}", other)),
+            };
+        } else if !bench && arg == "--profiles" {
+            all_profiles = true;
+        } else if arg == "--implemented" {
+            implemented_only = true;
+        } else if arg == "--example" {
+            input = InputSource::Example;
+        } else if arg == "--input" {
+            input = match args.next().ok_or("--input needs a path")?.as_str() {
+                "-" => InputSource::Stdin,
+                path => InputSource::Path(PathBuf::from(path)),
+            };
+        } else if arg == "--profile" {
+            input = InputSource::Profile(parse_name(arg, args.next())?);
+        } else if arg.starts_with("--") {
+            return Err(format!("Unknown option: {}", arg));
+        } else {
+            for term in arg.split(',').filter(|t| !t.is_empty()) {
+                if let Some(new_year) = parse_year(term)? {
+                    if let Some(bare) = bare_year.replace(new_year) {
+                        selected.extend(days::of_year(bare).map(|s| (s, None)));
+                    }
+                    year = new_year;
+                    continue;
+                }
+                match term.strip_prefix('!') {
+                    Some(days) => excluded.extend(parse_days(year, days)?),
+                    None => {
+                        bare_year = None;
+                        let (days, part) = match term.split_once(':') {
+                            Some((days, part)) => (days, Some(part.parse::<Part>()?)),
+                            None => (term, None),
+                        };
+                        selected.extend(parse_days(year, days)?.into_iter().map(|s| (s, part)));
+                    }
+                }
+            }
+        }
+    }
+    if let Some(bare) = bare_year {
+        selected.extend(days::of_year(bare).map(|s| (s, None)));
+    }
+
+    if selected.is_empty() {
+        if !implemented_only {
+            return Err("Please provide the day(s) to run as a command-line argument.".to_owned());
+        }
+        // Like `all`, this is every day of the year in effect.
+        selected = days::of_year(year).map(|s| (s, None)).collect();
+    }
+
+    let same = |a: &dyn Solver, b: &dyn Solver| a.year() == b.year() && a.number() == b.number();
+    let mut tasks: Vec<Task> = vec![];
+    for (solver, part) in selected {
+        if excluded.iter().any(|&e| same(e, solver)) || (implemented_only && !solver.implemented())
+        {
+            continue;
+        }
+        let parts = part.map_or_else(|| default_parts.clone(), |p| vec![p]);
+        match tasks.iter_mut().find(|t| same(t.solver, solver)) {
+            Some(task) => {
+                task.parts.extend(parts);
+                task.parts.sort();
+                task.parts.dedup();
+            }
+            None => tasks.push(Task { solver, parts }),
+        }
+    }
+
+    if tasks.is_empty() {
+        return Err("The selection leaves no days to run.".to_owned());
+    }
+    if lock && !matches!(input, InputSource::Puzzle | InputSource::Profile(_)) {
+        return Err("--lock only applies to puzzle inputs".to_owned());
+    }
+    if all_profiles && (lock || input != InputSource::Puzzle) {
+        return Err(
+            "--profiles reads every profile's input and can't be combined with \
+             --lock, --example, --input or --profile"
+                .to_owned(),
+        );
+    }
+
+    let run = RunArgs {
+        tasks,
+        input,
+        lock,
+        timeout,
+        jobs,
+        format,
+    };
+    if bench {
+        return Ok(Command::Bench(BenchArgs {
+            run,
+            iterations,
+            warmup,
+            baseline,
+        }));
+    }
+    if all_profiles {
+        return Ok(Command::Profiles(run));
+    }
+    Ok(Command::Run(run))
+}
+
+fn parse_new(args: &[String], config: &Config) -> Result<Command, String> {
+    // Unlike day selection, the year needn't have any days yet.
+    let (year, args) = match args.first() {
+        Some(year) if year.len() == 4 && year.bytes().all(|b| b.is_ascii_digit()) => {
+            (year.parse().expect("four digits fit a u16"), &args[1..])
+        }
+        _ => (days::default_year(config), args),
+    };
+    let day = args.first().ok_or("new needs a day")?;
+    let day = day
+        .parse::<u8>()
+        .ok()
+        .filter(|&d| d > 0)
+        .ok_or_else(|| format!("Not a valid day: {}", day))?;
+    let title = (args.len() > 1).then(|| args[1..].join(" "));
+    Ok(Command::New { year, day, title })
+}
+
+fn parse_compare(args: &[String]) -> Result<Command, String> {
+    let mut compare = CompareArgs {
+        baseline: None,
+        threshold: 10.0,
+    };
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--baseline" => compare.baseline = Some(parse_name(arg, args.next())?),
+            "--threshold" => {
+                let value = args.next().ok_or("--threshold needs a value")?;
+                compare.threshold = value
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|t| t.is_finite() && *t >= 0.0)
+                    .ok_or_else(|| format!("Not a valid threshold: {}", value))?;
+            }
+            _ => return Err(format!("Unknown option for compare: {}", arg)),
+        }
+    }
+    Ok(Command::Compare(compare))
+}
+
+/// Baseline names become file names, so keep them to a safe alphabet.
+fn parse_name(option: &str, value: Option<&String>) -> Result<String, String> {
+    let value = value.ok_or_else(|| format!("{} needs a value", option))?;
+    if value.is_empty()
+        || !value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(format!("Not a valid name for {}: {}", option, value));
+    }
+    Ok(value.clone())
+}
+
+fn parse_count(option: &str, value: Option<&String>) -> Result<usize, String> {
+    let value = value.ok_or_else(|| format!("{} needs a value", option))?;
+    value
+        .parse()
+        .map_err(|_| format!("Not a valid count for {}: {}", option, value))
+}
+
+/// Reads a four-digit term as a year, which must have registered days.
+fn parse_year(term: &str) -> Result<Option<u16>, String> {
+    if term.len() != 4 || !term.bytes().all(|b| b.is_ascii_digit()) {
+        return Ok(None);
+    }
+    let year = term.parse().expect("four digits fit a u16");
+    let years = days::years();
+    if !years.contains(&year) {
+        let years: Vec<String> = years.iter().map(u16::to_string).collect();
+        return Err(format!(
+            "No days registered for {} (available years: {})",
+            year,
+            years.join(", ")
+        ));
+    }
+    Ok(Some(year))
+}
+
+/// Expands a single day, a `<from>-<to>` range or `all` into registered
+/// days of `year`.
+fn parse_days(year: u16, expr: &str) -> Result<Vec<&'static dyn Solver>, String> {
+    if expr == "all" {
+        return Ok(days::of_year(year).collect());
+    }
+
+    let day = |s: &str| -> Result<u8, String> {
+        let day = s
+            .trim()
+            .parse::<u8>()
+            .map_err(|_| format!("Not a valid day: {}", s))?;
+        if days::get(year, day).is_none() {
+            let numbers: Vec<u8> = days::of_year(year).map(|s| s.number()).collect();
+            return Err(format!(
+                "Day {} is out of range (available days in {}: {}-{})",
+                day,
+                year,
+                numbers.first().unwrap_or(&0),
+                numbers.last().unwrap_or(&0)
+            ));
+        }
+        Ok(day)
+    };
+
+    match expr.split_once('-') {
+        Some((from, to)) => {
+            let (from, to) = (day(from)?, day(to)?);
+            if from > to {
+                return Err(format!("Not a valid range: {}", expr));
+            }
+            Ok((from..=to).filter_map(|d| days::get(year, d)).collect())
+        }
+        None => Ok(days::get(year, day(expr)?).into_iter().collect()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(args: &[&str]) -> RunArgs {
+        let args: Vec<String> = args.iter().map(|a| a.to_string()).collect();
+        match parse(&args, &Config::default()) {
+            Ok(Command::Run(run)) => run,
+            _ => panic!("expected a run command for {:?}", args),
+        }
+    }
+
+    #[test]
+    fn test_part_selection() {
+        let tasks = run(&["6:2", "1", "--part", "1"]).tasks;
+        assert_eq!(tasks[0].solver.number(), 6);
+        assert_eq!(tasks[0].parts, vec![Part::Two]);
+        assert_eq!(tasks[1].solver.number(), 1);
+        assert_eq!(tasks[1].parts, vec![Part::One]);
+
+        let tasks = run(&["4"]).tasks;
+        assert_eq!(tasks[0].parts, Part::ALL.to_vec());
+    }
+
+    #[test]
+    fn test_input_selection() {
+        assert_eq!(run(&["1"]).input, InputSource::Puzzle);
+        assert_eq!(run(&["1", "--example"]).input, InputSource::Example);
+        assert_eq!(run(&["1", "--input", "-"]).input, InputSource::Stdin);
+        assert_eq!(
+            run(&["--input", "x.txt", "1"]).input,
+            InputSource::Path(PathBuf::from("x.txt"))
+        );
+        assert_eq!(
+            run(&["1", "--profile", "alice", "--lock"]).input,
+            InputSource::Profile("alice".to_owned())
+        );
+
+        let parse = |args: &[&str]| {
+            let args: Vec<String> = args.iter().map(|a| a.to_string()).collect();
+            parse(&args, &Config::default())
+        };
+        assert!(matches!(
+            parse(&["1", "--profiles"]),
+            Ok(Command::Profiles(_))
+        ));
+        assert!(parse(&["1", "--profiles", "--example"]).is_err());
+        assert!(parse(&["1", "--profile", "../x"]).is_err());
+    }
+
+    fn selected_days(args: &[&str]) -> Vec<u8> {
+        run(args).tasks.iter().map(|t| t.solver.number()).collect()
+    }
+
+    #[test]
+    fn test_day_selection() {
+        assert_eq!(selected_days(&["1-3", "5"]), vec![1, 2, 3, 5]);
+        assert_eq!(selected_days(&["1-6,!2-4"]), vec![1, 5, 6]);
+        assert_eq!(
+            selected_days(&["all,!6"]).len(),
+            days::of_year(days::default_year(&Config::default())).count() - 1
+        );
+        assert_eq!(selected_days(&["--implemented"]), vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(selected_days(&["3,1,3"]), vec![3, 1]);
+
+        let tasks = run(&["6:1", "6:2"]).tasks;
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].parts, Part::ALL.to_vec());
+
+        let args: Vec<String> = vec!["26".to_owned()];
+        assert!(parse(&args, &Config::default()).is_err_and(|e| e.contains("out of range")));
+
+        assert_eq!(selected_days(&["run", "2024", "5"]), vec![5]);
+        assert_eq!(selected_days(&["2024"]).len(), days::of_year(2024).count());
+        assert_eq!(selected_days(&["2024", "!3-25"]), vec![1, 2]);
+        let args: Vec<String> = vec!["1999".to_owned(), "1".to_owned()];
+        assert!(parse(&args, &Config::default()).is_err_and(|e| e.contains("No days registered")));
+    }
+
+    #[test]
+    fn test_config_defaults() {
+        let mut config = Config::default();
+        config.run.format = Some(Format::Csv);
+        config.run.jobs = Some(4);
+        config.bench.iterations = Some(50);
+        let parse = |args: &[&str]| {
+            let args: Vec<String> = args.iter().map(|a| a.to_string()).collect();
+            parse(&args, &config)
+        };
+
+        match parse(&["1", "--jobs", "2"]) {
+            Ok(Command::Run(run)) => {
+                assert_eq!(run.format, Format::Csv);
+                assert_eq!(run.jobs, 2);
+            }
+            _ => panic!("expected a run command"),
+        }
+        match parse(&["bench", "1", "--warmup", "0"]) {
+            Ok(Command::Bench(bench)) => {
+                assert_eq!(bench.iterations, 50);
+                assert_eq!(bench.warmup, 0);
+            }
+            _ => panic!("expected a bench command"),
+        }
+
+        let config = Config {
+            year: Some(2015),
+            ..Config::default()
+        };
+        let parse = |args: &[&str]| {
+            let args: Vec<String> = args.iter().map(|a| a.to_string()).collect();
+            super::parse(&args, &config)
+        };
+        assert!(matches!(
+            parse(&["new", "1"]),
+            Ok(Command::New { year: 2015, .. })
+        ));
+        assert!(parse(&["1"]).is_err_and(|e| e.contains("available days in 2015")));
+        assert!(parse(&["--implemented"]).is_err_and(|e| e.contains("no days")));
+    }
+
+    #[test]
+    fn test_new_day() {
+        let args: Vec<String> = ["new", "7", "Bridge", "Repair"]
+            .iter()
+            .map(|a| a.to_string())
+            .collect();
+        assert!(matches!(
+            parse(&args, &Config::default()),
+            Ok(Command::New { year: 2024, day: 7, title: Some(t) }) if t == "Bridge Repair"
+        ));
+        let args: Vec<String> = vec!["new".to_owned(), "2015".to_owned(), "1".to_owned()];
+        assert!(matches!(
+            parse(&args, &Config::default()),
+            Ok(Command::New {
+                year: 2015,
+                day: 1,
+                title: None
+            })
+        ));
+        assert!(parse(&args[..1], &Config::default()).is_err());
+    }
+}
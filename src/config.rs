@@ -0,0 +1,190 @@
+use crate::cli::Format;
+use crate::etc::AocError;
+use serde::Deserialize;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// The file looked for in the current directory and each of its parents.
+pub const FILE_NAME: &str = "aoc.toml";
+/// Points at a config file to use instead of searching for one.
+pub const ENV_VAR: &str = "AOC_CONFIG";
+
+/// Project settings from `aoc.toml`. Every key is optional; command-line
+/// flags override whatever is set here.
+///
+/// ```toml
+/// input_dir = "input"
+/// year = 2024
+/// log = "info"
+///
+/// [run]
+/// format = "text"
+/// timeout = 10.0
+/// jobs = 1
+///
+/// [bench]
+/// iterations = 20
+/// warmup = 3
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// The directory holding the config file. Inputs, answers and bench
+    /// history live relative to it, so the runner works from any directory
+    /// below it. The current directory when there is no config file.
+    #[serde(skip)]
+    pub root: PathBuf,
+    /// Where puzzle and example inputs live, relative to `root`.
+    pub input_dir: Option<PathBuf>,
+    pub year: Option<u16>,
+    /// A `tracing` filter such as `info` or `warn,advent_of_code_template=debug`,
+    /// used unless `RUST_LOG` is set.
+    pub log: Option<String>,
+    #[serde(default)]
+    pub run: RunConfig,
+    #[serde(default)]
+    pub bench: BenchConfig,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RunConfig {
+    pub format: Option<Format>,
+    /// Seconds, as for `--timeout`.
+    pub timeout: Option<f64>,
+    pub jobs: Option<usize>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BenchConfig {
+    pub iterations: Option<usize>,
+    pub warmup: Option<usize>,
+}
+
+impl Config {
+    /// Reads the file named by `AOC_CONFIG`, or else the nearest `aoc.toml`
+    /// in the current directory or one of its parents. Without either, every
+    /// setting keeps its default and paths are relative to the current
+    /// directory.
+    pub fn load() -> Result<Self, AocError> {
+        let path = match env::var_os(ENV_VAR) {
+            Some(path) => Some(PathBuf::from(path)),
+            None => find(&env::current_dir().unwrap_or_default()),
+        };
+        match path {
+            Some(path) => Self::read(&path),
+            None => Ok(Config {
+                root: PathBuf::from("."),
+                ..Config::default()
+            }),
+        }
+    }
+
+    fn read(path: &Path) -> Result<Self, AocError> {
+        let content = fs::read_to_string(path).map_err(AocError::io(path))?;
+        let mut config: Config = toml::from_str(&content).map_err(|e| AocError::Config {
+            path: path.to_owned(),
+            message: e.message().to_owned(),
+        })?;
+        if config
+            .run
+            .timeout
+            .is_some_and(|t| !t.is_finite() || t <= 0.0)
+        {
+            return Err(AocError::Config {
+                path: path.to_owned(),
+                message: "run.timeout must be a positive number of seconds".to_owned(),
+            });
+        }
+        config.root = match path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir.to_owned(),
+            _ => PathBuf::from("."),
+        };
+        Ok(config)
+    }
+
+    /// The directory puzzle and example inputs are read from.
+    pub fn input_dir(&self) -> PathBuf {
+        self.root
+            .join(self.input_dir.as_deref().unwrap_or(Path::new("input")))
+    }
+}
+
+fn find(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .map(|dir| dir.join(FILE_NAME))
+        .find(|path| path.is_file())
+}
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// Makes `config` the one [`get`] returns. Only the first call has an effect.
+pub fn init(config: Config) -> &'static Config {
+    CONFIG.get_or_init(|| config)
+}
+
+/// The configuration set by [`init`], or the defaults relative to the
+/// current directory if it was never called.
+pub fn get() -> &'static Config {
+    CONFIG.get_or_init(|| Config {
+        root: PathBuf::from("."),
+        ..Config::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An empty scratch directory for one test.
+    fn scratch(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-config-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_find_and_read() {
+        let root = scratch("read");
+        let nested = root.join("src/y2024");
+        fs::create_dir_all(&nested).unwrap();
+        let path = root.join(FILE_NAME);
+        fs::write(
+            &path,
+            "input_dir = \"puzzles\"\nyear = 2023\n[run]\ntimeout = 2.5\n",
+        )
+        .unwrap();
+
+        assert_eq!(find(&nested), Some(path.clone()));
+        let config = Config::read(&path).unwrap();
+        assert_eq!(config.root, root);
+        assert_eq!(config.input_dir(), root.join("puzzles"));
+        assert_eq!(config.year, Some(2023));
+        assert_eq!(config.run.timeout, Some(2.5));
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        let root = scratch("invalid");
+        let path = root.join(FILE_NAME);
+        for content in [
+            "colour = \"red\"",
+            "[run]\ntimeout = 0",
+            "[bench]\njobs = 2",
+        ] {
+            fs::write(&path, content).unwrap();
+            assert!(
+                matches!(Config::read(&path), Err(AocError::Config { .. })),
+                "{:?} should be rejected",
+                content
+            );
+        }
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,39 @@
+use crate::config::Config;
+use crate::etc::Solver;
+
+// `build.rs` builds the `DAYS` registry from every `src/yYYYY/dayNN.rs`, so
+// adding a day only means adding its file.
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
+
+/// All registered days, ordered by year and then day.
+pub fn all() -> &'static [&'static dyn Solver] {
+    DAYS
+}
+
+/// Looks up a registered day by its year and number.
+pub fn get(year: u16, day: u8) -> Option<&'static dyn Solver> {
+    DAYS.iter()
+        .copied()
+        .find(|solver| solver.year() == year && solver.number() == day)
+}
+
+/// The registered days of one year.
+pub fn of_year(year: u16) -> impl Iterator<Item = &'static dyn Solver> {
+    DAYS.iter().copied().filter(move |s| s.year() == year)
+}
+
+/// Every year with at least one registered day, in ascending order.
+pub fn years() -> Vec<u16> {
+    let mut years: Vec<u16> = DAYS.iter().map(|s| s.year()).collect();
+    years.dedup();
+    years
+}
+
+/// The year days are picked from when none is given: `year` from
+/// `config`, or else the latest registered year.
+pub fn default_year(config: &Config) -> u16 {
+    config
+        .year
+        .or_else(|| years().last().copied())
+        .unwrap_or_default()
+}
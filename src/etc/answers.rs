@@ -0,0 +1,187 @@
+use super::{AocError, Part, Solution};
+use crate::config;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The locked-in answers for one day, read from `answers/YYYY/dayNN.txt`:
+///
+/// ```text
+/// part1: 11
+/// part2: 31
+/// ```
+///
+/// Either line may be missing while a part is still unsolved. Expected
+/// answers to an example input or an input profile sit next to it in the
+/// same format, e.g. `input/2024/day4_short.answers` for
+/// `input/2024/day4_short.txt`.
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    part1: Option<Solution>,
+    part2: Option<Solution>,
+}
+
+/// How an answer compares with the stored one.
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: Solution,
+    },
+    Unknown,
+    /// The part returned `Solution::Unsolved`; nothing to check.
+    Unsolved,
+}
+
+impl Answers {
+    pub fn path(year: u16, day: u8) -> PathBuf {
+        config::get()
+            .root
+            .join(format!("answers/{}/day{:02}.txt", year, day))
+    }
+
+    pub fn example_path(year: u16, day: u8) -> PathBuf {
+        config::get()
+            .input_dir()
+            .join(format!("{}/day{}_short.answers", year, day))
+    }
+
+    pub fn profile_path(year: u16, day: u8, profile: &str) -> PathBuf {
+        config::get()
+            .input_dir()
+            .join(format!("{}/{}/day{}.answers", year, profile, day))
+    }
+
+    /// Reads the answers stored at `path`; a missing file means there are
+    /// none.
+    pub fn read(path: PathBuf) -> Result<Self, AocError> {
+        match fs::read_to_string(&path) {
+            Ok(content) => Self::parse(&content),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(source) => Err(AocError::Io { path, source }),
+        }
+    }
+
+    pub fn parse(content: &str) -> Result<Self, AocError> {
+        let mut answers = Self::default();
+        for (n, line) in content.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let (key, value) = line.split_once(':').ok_or_else(|| {
+                AocError::parse(n + 1, 1, "expected `part1: <answer>` or `part2: <answer>`")
+            })?;
+            let part = match key.trim() {
+                "part1" => Part::One,
+                "part2" => Part::Two,
+                other => {
+                    return Err(AocError::parse(
+                        n + 1,
+                        1,
+                        format!("unknown key `{}` in answers", other),
+                    ))
+                }
+            };
+            let Ok(answer) = value.trim().parse();
+            answers.set(part, answer);
+        }
+        Ok(answers)
+    }
+
+    pub fn get(&self, part: Part) -> Option<&Solution> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+
+    pub fn set(&mut self, part: Part, answer: Solution) {
+        match part {
+            Part::One => self.part1 = Some(answer),
+            Part::Two => self.part2 = Some(answer),
+        }
+    }
+
+    /// Writes the answers to `path`. Answers that span several lines are
+    /// refused, as they couldn't be read back.
+    pub fn write(&self, path: &Path) -> Result<(), AocError> {
+        let mut content = String::new();
+        for part in Part::ALL {
+            if let Some(answer) = self.get(part) {
+                let answer = answer.to_string();
+                if answer.contains(['\n', '\r']) {
+                    return Err(AocError::MultilineAnswer {
+                        path: path.to_owned(),
+                        part,
+                    });
+                }
+                content.push_str(&format!("part{}: {}\n", part, answer));
+            }
+        }
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(AocError::io(dir))?;
+        }
+        fs::write(path, content).map_err(AocError::io(path))
+    }
+
+    pub fn check(&self, part: Part, answer: &Solution) -> Verdict {
+        if !answer.is_solved() {
+            return Verdict::Unsolved;
+        }
+        match self.get(part) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.clone(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse("part1: 11\n").unwrap();
+        assert_eq!(
+            answers.check(Part::One, &Solution::from(11u64)),
+            Verdict::Pass
+        );
+        assert_eq!(
+            answers.check(Part::One, &Solution::from(12u64)),
+            Verdict::Fail {
+                expected: Solution::from(11u64)
+            }
+        );
+        assert_eq!(
+            answers.check(Part::Two, &Solution::from(0u64)),
+            Verdict::Unknown
+        );
+        assert_eq!(
+            answers.check(Part::One, &Solution::from(11i32)),
+            Verdict::Pass
+        );
+        assert_eq!(
+            answers.check(Part::One, &Solution::Unsolved),
+            Verdict::Unsolved
+        );
+        let answers = Answers::parse("part1: 01029498\n").unwrap();
+        assert_eq!(
+            answers.check(Part::One, &Solution::from("01029498")),
+            Verdict::Pass
+        );
+        assert!(Answers::parse("part3: 1").is_err());
+
+        let mut answers = Answers::default();
+        answers.set(Part::One, Solution::grid("#.\n.#"));
+        assert!(matches!(
+            answers.write(Path::new("unused.txt")),
+            Err(AocError::MultilineAnswer {
+                part: Part::One,
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,113 @@
+use super::AocError;
+use crate::Solution;
+use std::any::Any;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// One of the two halves of a puzzle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => "1".fmt(f),
+            Part::Two => "2".fmt(f),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("Not a valid part: {} (expected 1 or 2)", s)),
+        }
+    }
+}
+
+/// A single puzzle day: how to parse its input and how to solve each part.
+///
+/// The parsed input is shared between both parts, so anything expensive to
+/// build from the raw text belongs in `parse`.
+pub trait Day {
+    const YEAR: u16;
+    const NUMBER: u8;
+    const TITLE: &'static str;
+    /// Stub days leave this `false` so they can be skipped by the runner.
+    const IMPLEMENTED: bool = true;
+
+    type Input: 'static;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError>;
+    fn part1(input: &Self::Input) -> Result<Solution, AocError>;
+    fn part2(input: &Self::Input) -> Result<Solution, AocError>;
+}
+
+/// Object-safe view of a [`Day`], as stored in the registry.
+pub trait Solver: Sync {
+    fn year(&self) -> u16;
+    fn number(&self) -> u8;
+    fn title(&self) -> &'static str;
+    fn implemented(&self) -> bool;
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, AocError>;
+    fn part1(&self, input: &dyn Any) -> Result<Solution, AocError>;
+    fn part2(&self, input: &dyn Any) -> Result<Solution, AocError>;
+
+    fn solve(&self, part: Part, input: &dyn Any) -> Result<Solution, AocError> {
+        match part {
+            Part::One => self.part1(input),
+            Part::Two => self.part2(input),
+        }
+    }
+}
+
+impl<D: Day + Sync> Solver for D {
+    fn year(&self) -> u16 {
+        D::YEAR
+    }
+
+    fn number(&self) -> u8 {
+        D::NUMBER
+    }
+
+    fn title(&self) -> &'static str {
+        D::TITLE
+    }
+
+    fn implemented(&self) -> bool {
+        D::IMPLEMENTED
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, AocError> {
+        Ok(Box::new(<D as Day>::parse(input)?))
+    }
+
+    fn part1(&self, input: &dyn Any) -> Result<Solution, AocError> {
+        <D as Day>::part1(downcast::<D>(input))
+    }
+
+    fn part2(&self, input: &dyn Any) -> Result<Solution, AocError> {
+        <D as Day>::part2(downcast::<D>(input))
+    }
+}
+
+fn downcast<D: Day>(input: &dyn Any) -> &D::Input {
+    input.downcast_ref::<D::Input>().unwrap_or_else(|| {
+        panic!(
+            "Day {} of {} was handed input it did not parse",
+            D::NUMBER,
+            D::YEAR
+        )
+    })
+}
@@ -0,0 +1,107 @@
+use super::Part;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Everything that can go wrong between reading a day's input and producing
+/// an answer, short of a bug in the solver itself.
+#[derive(Debug)]
+pub enum AocError {
+    MissingInput {
+        path: PathBuf,
+        source: io::Error,
+    },
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    InvalidGrid(String),
+    /// The input parsed, but describes something the puzzle can't solve.
+    InvalidInput(String),
+    /// Reading or writing one of the runner's own files failed.
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+    /// An answer spanning several lines, such as a grid whose letters
+    /// weren't recognised, can't go in a line-based answers file.
+    MultilineAnswer {
+        path: PathBuf,
+        part: Part,
+    },
+    /// `new` would overwrite a day that already has a solution.
+    AlreadySolved(PathBuf),
+    /// `aoc.toml` could not be understood.
+    Config {
+        path: PathBuf,
+        message: String,
+    },
+}
+
+impl AocError {
+    /// A parse error at a 1-based line and column.
+    pub fn parse(line: usize, column: usize, message: impl Into<String>) -> Self {
+        AocError::Parse {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// Wraps an I/O error from reading or writing `path`, for `map_err`.
+    pub fn io(path: &Path) -> impl FnOnce(io::Error) -> Self + '_ {
+        move |source| AocError::Io {
+            path: path.to_owned(),
+            source,
+        }
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::MissingInput { path, source } => {
+                write!(f, "missing input {}: {}", path.display(), source)
+            }
+            AocError::Parse {
+                line,
+                column,
+                message,
+            } => write!(
+                f,
+                "parse error at line {}, column {}: {}",
+                line, column, message
+            ),
+            AocError::InvalidGrid(message) => write!(f, "invalid grid: {}", message),
+            AocError::InvalidInput(message) => write!(f, "invalid input: {}", message),
+            AocError::Io { path, source } => {
+                write!(f, "could not access {}: {}", path.display(), source)
+            }
+            AocError::MultilineAnswer { path, part } => write!(
+                f,
+                "cannot store the part {} answer in {}: it spans several lines",
+                part,
+                path.display()
+            ),
+            AocError::AlreadySolved(path) => write!(
+                f,
+                "{} already has a solution; refusing to overwrite it",
+                path.display()
+            ),
+            AocError::Config { path, message } => {
+                write!(f, "invalid config {}: {}", path.display(), message)
+            }
+        }
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AocError::MissingInput { source, .. } | AocError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
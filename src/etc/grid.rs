@@ -0,0 +1,26 @@
+use super::AocError;
+
+/// Reads a rectangular character grid, returning its columns, rows and the
+/// cells in row-major order.
+pub fn parse_char_grid(input: &str) -> Result<(usize, usize, Vec<char>), AocError> {
+    let lines: Vec<&str> = input.lines().collect();
+    let cols = lines.first().map_or(0, |l| l.chars().count());
+    if cols == 0 {
+        return Err(AocError::InvalidGrid("input is empty".to_owned()));
+    }
+
+    let mut data = Vec::with_capacity(cols * lines.len());
+    for (row, line) in lines.iter().enumerate() {
+        let width = line.chars().count();
+        if width != cols {
+            return Err(AocError::InvalidGrid(format!(
+                "row {} is {} wide, expected {}",
+                row + 1,
+                width,
+                cols
+            )));
+        }
+        data.extend(line.chars());
+    }
+    Ok((cols, lines.len(), data))
+}
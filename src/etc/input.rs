@@ -0,0 +1,93 @@
+use super::{Answers, AocError};
+use crate::config;
+use std::fs::{self, read_to_string};
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+/// Where the runner reads a day's puzzle input from.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum InputSource {
+    /// `input/YYYY/dayN.txt`
+    #[default]
+    Puzzle,
+    /// `input/YYYY/dayN_short.txt`
+    Example,
+    /// `input/YYYY/<profile>/dayN.txt`, someone else's puzzle input.
+    Profile(String),
+    /// An explicit file, used for every selected day.
+    Path(PathBuf),
+    /// Standard input, read once and shared by every selected day.
+    Stdin,
+}
+
+impl InputSource {
+    /// The file this source reads for `day` of `year`, if it reads a file
+    /// at all.
+    pub fn path(&self, year: u16, day: u8) -> Option<PathBuf> {
+        let dir = || config::get().input_dir().join(year.to_string());
+        match self {
+            InputSource::Puzzle => Some(dir().join(format!("day{}.txt", day))),
+            InputSource::Example => Some(dir().join(format!("day{}_short.txt", day))),
+            InputSource::Profile(name) => Some(dir().join(name).join(format!("day{}.txt", day))),
+            InputSource::Path(path) => Some(path.clone()),
+            InputSource::Stdin => None,
+        }
+    }
+
+    /// The answers that go with this source's input, if any are kept for
+    /// it.
+    pub fn answers_path(&self, year: u16, day: u8) -> Option<PathBuf> {
+        match self {
+            InputSource::Puzzle => Some(Answers::path(year, day)),
+            InputSource::Example => Some(Answers::example_path(year, day)),
+            InputSource::Profile(name) => Some(Answers::profile_path(year, day, name)),
+            InputSource::Path(_) | InputSource::Stdin => None,
+        }
+    }
+
+    /// The input profiles of `year`: every directory under `input/YYYY`,
+    /// sorted by name.
+    pub fn profiles(year: u16) -> Result<Vec<String>, AocError> {
+        let dir = config::get().input_dir().join(year.to_string());
+        let entries = match fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(source) => return Err(AocError::Io { path: dir, source }),
+        };
+        let mut profiles = vec![];
+        for entry in entries {
+            let entry = entry.map_err(AocError::io(&dir))?;
+            if entry.path().is_dir() {
+                profiles.extend(entry.file_name().to_str().map(str::to_owned));
+            }
+        }
+        profiles.sort();
+        Ok(profiles)
+    }
+
+    pub fn load(&self, year: u16, day: u8) -> Result<String, AocError> {
+        match self.path(year, day) {
+            Some(path) => {
+                read_to_string(&path).map_err(|source| AocError::MissingInput { path, source })
+            }
+            None => read_stdin(),
+        }
+    }
+}
+
+fn read_stdin() -> Result<String, AocError> {
+    static STDIN: OnceLock<String> = OnceLock::new();
+    if let Some(content) = STDIN.get() {
+        return Ok(content.clone());
+    }
+
+    let mut content = String::new();
+    io::stdin()
+        .read_to_string(&mut content)
+        .map_err(|source| AocError::MissingInput {
+            path: PathBuf::from("<stdin>"),
+            source,
+        })?;
+    Ok(STDIN.get_or_init(|| content).clone())
+}
@@ -1,3 +1,15 @@
+pub mod answers;
+pub mod day;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod ocr;
+pub mod parse;
 pub mod solution;
 
+pub use answers::{Answers, Verdict};
+pub use day::{Day, Part, Solver};
+pub use error::AocError;
+pub use input::InputSource;
+pub use num_bigint::{BigInt, BigUint};
 pub use solution::Solution;
@@ -0,0 +1,93 @@
+//! Reads letters drawn on a grid, as some puzzles answer with, using the
+//! 6-pixel-high block font Advent of Code draws them in.
+
+/// Every letter of the 6-high font, drawn without blank columns around it.
+const GLYPHS: &[(char, [&str; 6])] = &[
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', ["###", ".#.", ".#.", ".#.", ".#.", "###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Whether a character of drawn art is a lit pixel.
+pub fn is_lit(c: char) -> bool {
+    matches!(c, '#' | '█')
+}
+
+/// Recognises the letters drawn in `art`, where lit pixels are `#` or `█`
+/// and anything else is blank. Letters are split on blank columns, so it
+/// doesn't matter how far apart they are drawn. Returns `None` unless every
+/// letter is in the font.
+pub fn recognise(art: &str) -> Option<String> {
+    let rows: Vec<Vec<bool>> = art
+        .lines()
+        .map(|l| l.chars().map(is_lit).collect())
+        .skip_while(|r: &Vec<bool>| !r.contains(&true))
+        .collect();
+    let height = rows.len() - rows.iter().rev().take_while(|r| !r.contains(&true)).count();
+    if height != 6 {
+        return None;
+    }
+    let rows = &rows[..height];
+    let width = rows.iter().map(Vec::len).max()?;
+    let lit = |row: usize, col: usize| rows[row].get(col).copied().unwrap_or(false);
+    let blank_column = |col: usize| (0..height).all(|row| !lit(row, col));
+
+    let mut text = String::new();
+    let mut col = 0;
+    while col < width {
+        if blank_column(col) {
+            col += 1;
+            continue;
+        }
+        let start = col;
+        while col < width && !blank_column(col) {
+            col += 1;
+        }
+        let (letter, _) = GLYPHS.iter().find(|(_, glyph)| {
+            glyph.iter().enumerate().all(|(row, line)| {
+                line.len() == col - start
+                    && line
+                        .chars()
+                        .enumerate()
+                        .all(|(i, c)| (c == '#') == lit(row, start + i))
+            })
+        })?;
+        text.push(*letter);
+    }
+    (!text.is_empty()).then_some(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recognise() {
+        let art = "\
+#..#.###..####.#...#
+#..#..#...#....#...#
+####..#...###...#.#.
+#..#..#...#......#..
+#..#..#...#......#..
+#..#.###..####...#..";
+        assert_eq!(recognise(art).as_deref(), Some("HIEY"));
+        assert_eq!(recognise(&art.replace('#', "█")).as_deref(), Some("HIEY"));
+        assert_eq!(recognise(&art.replacen('#', ".", 1)), None);
+        assert_eq!(recognise("##\n##"), None);
+    }
+}
@@ -0,0 +1,47 @@
+use super::AocError;
+use std::str::FromStr;
+
+/// Splits a line on ASCII whitespace, yielding each field with its 1-based column.
+pub fn fields(line: &str) -> impl Iterator<Item = (usize, &str)> {
+    line.split_ascii_whitespace()
+        .map(move |field| (field.as_ptr() as usize - line.as_ptr() as usize + 1, field))
+}
+
+/// Parses a single number, reporting where it came from on failure.
+pub fn number<T: FromStr>(field: &str, line: usize, column: usize) -> Result<T, AocError> {
+    field.parse().map_err(|_| {
+        AocError::parse(
+            line,
+            column,
+            format!("expected a number, found `{}`", field),
+        )
+    })
+}
+
+/// Converts a byte offset into `input` into a 1-based line and column.
+pub fn position(input: &str, offset: usize) -> (usize, usize) {
+    let before = &input[..offset];
+    let line = before.matches('\n').count() + 1;
+    let column = offset - before.rfind('\n').map_or(0, |i| i + 1) + 1;
+    (line, column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_positions() {
+        let fields: Vec<_> = fields("3   4").collect();
+        assert_eq!(fields, vec![(1, "3"), (5, "4")]);
+        assert_eq!(position("ab\ncd", 4), (2, 2));
+        assert!(matches!(
+            number::<u32>("x", 3, 7),
+            Err(AocError::Parse {
+                line: 3,
+                column: 7,
+                ..
+            })
+        ));
+    }
+}
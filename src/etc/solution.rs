@@ -1,7 +1,21 @@
+use super::ocr;
+use num_bigint::{BigInt, BigUint};
+use std::cmp::Ordering;
+use std::convert::Infallible;
 use std::fmt::{Display, Formatter, Result};
+use std::str::FromStr;
 use Solution::*;
 
-#[derive(Clone)]
+/// An answer to one part of a day.
+///
+/// The textual form from `Display` is also the stable serialized form:
+/// integers are written in decimal and strings as they are, and `FromStr`
+/// reads it back into an equal `Solution`. Integers compare by value across
+/// variants, so `I32(5) == U64(5)`. Any other pair except `Unsolved` compares
+/// by text, so `Str("5") == U64(5)`, a stored `01029498` matches the string
+/// answer `"01029498"`, a stored `6,4` matches `Coord(6, 4)` and a stored `HI`
+/// matches a grid with those letters drawn on it.
+#[derive(Clone, Debug)]
 pub enum Solution {
     I8(i8),
     I16(i16),
@@ -15,7 +29,186 @@ pub enum Solution {
     U64(u64),
     U128(u128),
     Usize(usize),
+    Big(BigInt),
     Str(String),
+    /// Written as its items joined with commas, e.g. `1,0,3`.
+    List(Vec<Solution>),
+    /// An `x,y` position.
+    Coord(i64, i64),
+    /// Letters drawn on a grid, one line per row with `#` for lit pixels.
+    /// Written as the letters it shows, or the art itself if they can't be
+    /// recognised. Build one with [`Solution::grid`].
+    Grid(String),
+    /// Returned by a part that has not been solved yet, so it is not
+    /// mistaken for a (wrong) answer.
+    Unsolved,
+}
+
+impl Solution {
+    /// The name of the variant holding the answer, e.g. `U64`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            I8(_) => "I8",
+            I16(_) => "I16",
+            I32(_) => "I32",
+            I64(_) => "I64",
+            I128(_) => "I128",
+            Isize(_) => "Isize",
+            U8(_) => "U8",
+            U16(_) => "U16",
+            U32(_) => "U32",
+            U64(_) => "U64",
+            U128(_) => "U128",
+            Usize(_) => "Usize",
+            Big(_) => "Big",
+            Str(_) => "Str",
+            List(_) => "List",
+            Coord(..) => "Coord",
+            Grid(_) => "Grid",
+            Unsolved => "Unsolved",
+        }
+    }
+
+    /// A grid answer from drawn art: `#` and `█` are lit pixels, anything
+    /// else is blank.
+    pub fn grid(art: &str) -> Self {
+        let rows: Vec<String> = art
+            .lines()
+            .map(|l| {
+                l.chars()
+                    .map(|c| if ocr::is_lit(c) { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        Grid(rows.join("\n"))
+    }
+
+    pub fn is_solved(&self) -> bool {
+        !matches!(self, Unsolved)
+    }
+
+    /// Parses `text` into the variant named by `kind`, as returned by
+    /// [`Solution::kind`], so an export's `answer` and `type` columns
+    /// round-trip exactly. Grids whose letters were recognised come back as
+    /// those letters in a `Str`.
+    pub fn parse_as(kind: &str, text: &str) -> std::result::Result<Self, String> {
+        fn int<T: FromStr>(text: &str, kind: &str) -> std::result::Result<T, String> {
+            text.parse()
+                .map_err(|_| format!("`{}` is not a valid {}", text, kind))
+        }
+        Ok(match kind {
+            "I8" => I8(int(text, kind)?),
+            "I16" => I16(int(text, kind)?),
+            "I32" => I32(int(text, kind)?),
+            "I64" => I64(int(text, kind)?),
+            "I128" => I128(int(text, kind)?),
+            "Isize" => Isize(int(text, kind)?),
+            "U8" => U8(int(text, kind)?),
+            "U16" => U16(int(text, kind)?),
+            "U32" => U32(int(text, kind)?),
+            "U64" => U64(int(text, kind)?),
+            "U128" => U128(int(text, kind)?),
+            "Usize" => Usize(int(text, kind)?),
+            "Big" => Big(int(text, kind)?),
+            "Str" => Str(text.to_owned()),
+            "List" if text.is_empty() => List(vec![]),
+            "List" => List(
+                text.split(',')
+                    .map(|item| {
+                        let Ok(item) = item.parse();
+                        item
+                    })
+                    .collect(),
+            ),
+            "Coord" => {
+                let (x, y) = text
+                    .split_once(',')
+                    .ok_or_else(|| format!("`{}` is not a valid Coord", text))?;
+                Coord(int(x, kind)?, int(y, kind)?)
+            }
+            "Grid" if text.contains('\n') => Self::grid(text),
+            "Grid" => Str(text.to_owned()),
+            "Unsolved" => Unsolved,
+            other => return Err(format!("unknown solution type `{}`", other)),
+        })
+    }
+
+    /// Integer answers widened so that any two can be compared.
+    fn integer(&self) -> Option<BigInt> {
+        Some(match self {
+            I8(x) => (*x).into(),
+            I16(x) => (*x).into(),
+            I32(x) => (*x).into(),
+            I64(x) => (*x).into(),
+            I128(x) => (*x).into(),
+            Isize(x) => (*x).into(),
+            U8(x) => (*x).into(),
+            U16(x) => (*x).into(),
+            U32(x) => (*x).into(),
+            U64(x) => (*x).into(),
+            U128(x) => (*x).into(),
+            Usize(x) => (*x).into(),
+            Big(x) => x.clone(),
+            Str(_) | List(_) | Coord(..) | Grid(_) | Unsolved => return None,
+        })
+    }
+}
+
+impl PartialEq for Solution {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl Eq for Solution {}
+
+/// Integers order by value, coordinates by `x` then `y` and other text,
+/// lists included, lexically. An integer and text are equal if the text is
+/// how the integer is written and unordered otherwise; `Unsolved` is
+/// unordered against anything but itself.
+impl PartialOrd for Solution {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Unsolved, Unsolved) => Some(Ordering::Equal),
+            (Unsolved, _) | (_, Unsolved) => None,
+            (Coord(x1, y1), Coord(x2, y2)) => Some((x1, y1).cmp(&(x2, y2))),
+            _ => match (self.integer(), other.integer()) {
+                (Some(a), Some(b)) => Some(a.cmp(&b)),
+                (None, None) => Some(self.to_string().cmp(&other.to_string())),
+                _ => (self.to_string() == other.to_string()).then_some(Ordering::Equal),
+            },
+        }
+    }
+}
+
+/// Reads the textual form back: integers become `U64`/`I64` (or `U128`/
+/// `I128`, then `Big`, if they do not fit), `unsolved` becomes `Unsolved` and
+/// anything else a `Str`. Digits that aren't how an integer is written, such
+/// as `007` or `+7`, stay text.
+impl FromStr for Solution {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// Only text written the way the integer displays itself.
+        fn exact<T: FromStr + ToString>(s: &str) -> Option<T> {
+            s.parse().ok().filter(|x: &T| x.to_string() == s)
+        }
+        Ok(if let Some(x) = exact::<u64>(s) {
+            U64(x)
+        } else if let Some(x) = exact::<i64>(s) {
+            I64(x)
+        } else if let Some(x) = exact::<u128>(s) {
+            U128(x)
+        } else if let Some(x) = exact::<i128>(s) {
+            I128(x)
+        } else if let Some(x) = exact::<BigInt>(s) {
+            Big(x)
+        } else if s == "unsolved" {
+            Unsolved
+        } else {
+            Str(s.to_owned())
+        })
+    }
 }
 
 impl Display for Solution {
@@ -33,7 +226,23 @@ impl Display for Solution {
             U64(x) => x.fmt(f),
             U128(x) => x.fmt(f),
             Usize(x) => x.fmt(f),
+            Big(x) => x.fmt(f),
             Str(x) => x.fmt(f),
+            List(items) => {
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(",")?;
+                    }
+                    item.fmt(f)?;
+                }
+                Ok(())
+            }
+            Coord(x, y) => write!(f, "{},{}", x, y),
+            Grid(art) => match ocr::recognise(art) {
+                Some(text) => f.write_str(&text),
+                None => f.write_str(art),
+            },
+            Unsolved => f.write_str("unsolved"),
         }
     }
 }
@@ -60,10 +269,91 @@ impl_from!(u32, U32);
 impl_from!(u64, U64);
 impl_from!(u128, U128);
 impl_from!(usize, Usize);
+impl_from!(BigInt, Big);
 impl_from!(String, Str);
 
+impl<T: Into<Solution>> From<Vec<T>> for Solution {
+    fn from(sol: Vec<T>) -> Self {
+        Self::List(sol.into_iter().map(Into::into).collect())
+    }
+}
+
+macro_rules! impl_from_coord {
+    ($($type_:ident),*) => {
+        $(
+            impl From<($type_, $type_)> for Solution {
+                fn from((x, y): ($type_, $type_)) -> Self {
+                    let coord = |n| i64::try_from(n).expect("coordinate does not fit an i64");
+                    Self::Coord(coord(x), coord(y))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_coord!(i32, i64, isize, u32, usize);
+
+impl From<BigUint> for Solution {
+    fn from(sol: BigUint) -> Self {
+        Self::Big(sol.into())
+    }
+}
+
 impl From<&str> for Solution {
     fn from(sol: &str) -> Self {
         Self::Str(sol.to_owned())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare_and_round_trip() {
+        assert_eq!(Solution::from(5i32), Solution::from(5u64));
+        assert_ne!(Solution::from(-1i8), Solution::from(u128::MAX));
+        assert!(Solution::from(-1i64) < Solution::from(0u8));
+        assert!(Solution::from(u128::MAX) > Solution::from(i128::MAX));
+        assert_eq!(Solution::from(BigUint::from(7u8)), Solution::from(7i8));
+        assert_eq!(Solution::from("5"), Solution::from(5u64));
+        assert_eq!(Solution::from(5u64).partial_cmp(&Solution::from("6")), None);
+        assert_eq!("007".parse::<Solution>().unwrap().kind(), "Str");
+        assert_eq!("+7".parse::<Solution>().unwrap().kind(), "Str");
+
+        for answer in [
+            Solution::from(-12i32),
+            Solution::from(u128::MAX),
+            Solution::from(BigInt::from(u128::MAX) * -3),
+            Solution::from("LGHEKLAZ"),
+            Solution::from("01029498"),
+            Solution::Unsolved,
+        ] {
+            let text = answer.to_string();
+            assert_eq!(text.parse::<Solution>().unwrap(), answer);
+            let exact = Solution::parse_as(answer.kind(), &text).unwrap();
+            assert_eq!(exact.kind(), answer.kind());
+            assert_eq!(exact, answer);
+        }
+        assert!(Solution::parse_as("U8", "256").is_err());
+    }
+
+    #[test]
+    fn test_composite_answers() {
+        let list = Solution::from(vec![4u8, 6, 3]);
+        assert_eq!(list.to_string(), "4,6,3");
+        assert_eq!(list, "4,6,3".parse().unwrap());
+        assert!(list < Solution::from(vec![4u64, 7]));
+        assert_eq!(Solution::from(vec![5u64]), Solution::from(vec!["5"]));
+
+        let coord = Solution::from((6usize, 4usize));
+        assert_eq!(coord.to_string(), "6,4");
+        assert_eq!(Solution::parse_as("Coord", "-1,2").unwrap(), Coord(-1, 2));
+        assert!(Coord(1, 9) < Coord(2, 0));
+
+        let art = ".##.\n#..#\n#..#\n####\n#..#\n#..#";
+        assert_eq!(Solution::grid(&art.replace('.', " ")).to_string(), "A");
+        assert_eq!(Solution::grid(art), Solution::from("A"));
+        assert_eq!(Solution::grid("#.\n.#").to_string(), "#.\n.#");
+    }
+}
@@ -0,0 +1,240 @@
+use crate::config;
+use crate::etc::AocError;
+use std::collections::HashMap;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const HEADER: &str = "run,commit,year,day,phase,median_ns";
+
+/// The median time of one benchmarked phase (`parse`, `part1` or `part2`)
+/// of one day, as recorded by a single bench run.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Entry {
+    /// Milliseconds since the Unix epoch when the run was recorded; shared by
+    /// every entry of the same run.
+    pub run: u128,
+    pub commit: String,
+    pub year: u16,
+    pub day: u8,
+    pub phase: String,
+    pub median_ns: u64,
+}
+
+fn history_path(root: &Path) -> PathBuf {
+    root.join(".aoc/bench_history.csv")
+}
+
+fn baseline_path(root: &Path, name: &str) -> PathBuf {
+    root.join(format!(".aoc/baselines/{}.csv", name))
+}
+
+/// The short hash of `HEAD`, or an empty string outside a git checkout.
+fn git_commit() -> String {
+    Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|out| out.status.success())
+        .map(|out| String::from_utf8_lossy(&out.stdout).trim().to_owned())
+        .unwrap_or_default()
+}
+
+/// Stamps `(year, day, phase, median_ns)` measurements with the current
+/// time and commit, appends them to the history and, if asked, saves them as
+/// a named baseline.
+pub fn record(
+    measurements: &[(u16, u8, &str, u64)],
+    baseline: Option<&str>,
+) -> Result<Vec<Entry>, AocError> {
+    let run = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis());
+    record_in(&config::get().root, run, measurements, baseline)
+}
+
+fn record_in(
+    root: &Path,
+    run: u128,
+    measurements: &[(u16, u8, &str, u64)],
+    baseline: Option<&str>,
+) -> Result<Vec<Entry>, AocError> {
+    let commit = git_commit();
+    let entries: Vec<Entry> = measurements
+        .iter()
+        .map(|&(year, day, phase, median_ns)| Entry {
+            run,
+            commit: commit.clone(),
+            year,
+            day,
+            phase: phase.to_owned(),
+            median_ns,
+        })
+        .collect();
+
+    append(&history_path(root), &entries)?;
+    if let Some(name) = baseline {
+        let path = baseline_path(root, name);
+        if path.exists() {
+            fs::remove_file(&path).map_err(AocError::io(&path))?;
+        }
+        append(&path, &entries)?;
+    }
+    Ok(entries)
+}
+
+fn append(path: &Path, entries: &[Entry]) -> Result<(), AocError> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(AocError::io(dir))?;
+    }
+    let new = !path.exists();
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(AocError::io(path))?;
+
+    let mut out = String::new();
+    if new {
+        out.push_str(HEADER);
+        out.push('\n');
+    }
+    for e in entries {
+        out.push_str(&format!(
+            "{},{},{},{},{},{}\n",
+            e.run, e.commit, e.year, e.day, e.phase, e.median_ns
+        ));
+    }
+    file.write_all(out.as_bytes()).map_err(AocError::io(path))
+}
+
+fn read(path: &Path) -> Result<Vec<Entry>, AocError> {
+    let content = fs::read_to_string(path).map_err(AocError::io(path))?;
+    let mut entries = vec![];
+    for (n, line) in content.lines().enumerate().skip(1) {
+        let fields: Vec<&str> = line.split(',').collect();
+        let bad = || AocError::parse(n + 1, 1, format!("malformed entry in {}", path.display()));
+        let [run, commit, year, day, phase, median_ns] = fields[..] else {
+            return Err(bad());
+        };
+        entries.push(Entry {
+            run: run.parse().map_err(|_| bad())?,
+            commit: commit.to_owned(),
+            year: year.parse().map_err(|_| bad())?,
+            day: day.parse().map_err(|_| bad())?,
+            phase: phase.to_owned(),
+            median_ns: median_ns.parse().map_err(|_| bad())?,
+        });
+    }
+    Ok(entries)
+}
+
+/// How one phase moved between two measurements.
+pub struct Change {
+    pub year: u16,
+    pub day: u8,
+    pub phase: String,
+    pub before: Entry,
+    pub after: Entry,
+}
+
+impl Change {
+    /// Relative change in percent; positive means slower.
+    pub fn percent(&self) -> f64 {
+        (self.after.median_ns as f64 / self.before.median_ns.max(1) as f64 - 1.0) * 100.0
+    }
+}
+
+/// Pairs every phase of the latest recorded run with the baseline `name`,
+/// or with the most recent earlier run that measured the same phase.
+pub fn compare(baseline: Option<&str>) -> Result<Vec<Change>, AocError> {
+    compare_in(&config::get().root, baseline)
+}
+
+fn compare_in(root: &Path, baseline: Option<&str>) -> Result<Vec<Change>, AocError> {
+    let path = history_path(root);
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+    let history = read(&path)?;
+    let Some(latest) = history.iter().map(|e| e.run).max() else {
+        return Ok(vec![]);
+    };
+
+    let mut before: HashMap<(u16, u8, &str), &Entry> = HashMap::new();
+    let baseline_entries = match baseline {
+        Some(name) => read(&baseline_path(root, name))?,
+        None => vec![],
+    };
+    let candidates = match baseline {
+        Some(_) => &baseline_entries,
+        None => &history,
+    };
+    for e in candidates
+        .iter()
+        .filter(|e| e.run < latest || baseline.is_some())
+    {
+        let key = (e.year, e.day, e.phase.as_str());
+        if before.get(&key).is_none_or(|b| b.run <= e.run) {
+            before.insert(key, e);
+        }
+    }
+
+    let mut changes: Vec<Change> = history
+        .iter()
+        .filter(|e| e.run == latest)
+        .filter_map(|after| {
+            let before = before.get(&(after.year, after.day, after.phase.as_str()))?;
+            Some(Change {
+                year: after.year,
+                day: after.day,
+                phase: after.phase.clone(),
+                before: (*before).clone(),
+                after: after.clone(),
+            })
+        })
+        .collect();
+    changes.sort_by(|a, b| (a.year, a.day, &a.phase).cmp(&(b.year, b.day, &b.phase)));
+    Ok(changes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_record_and_compare() {
+        let root = env::temp_dir().join(format!("aoc-history-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+
+        record_in(&root, 1, &[(2024, 1, "parse", 100)], Some("base")).unwrap();
+        record_in(&root, 2, &[(2024, 1, "parse", 300)], None).unwrap();
+        let latest = record_in(
+            &root,
+            3,
+            &[(2024, 1, "parse", 150), (2024, 1, "part1", 50)],
+            None,
+        )
+        .unwrap();
+        assert_eq!(read(&history_path(&root)).unwrap().len(), 4);
+        assert_eq!(read(&history_path(&root)).unwrap()[2..], latest[..]);
+
+        // `part1` was never measured before, so only `parse` pairs up.
+        let changes = compare_in(&root, None).unwrap();
+        assert_eq!(changes.len(), 1);
+        assert_eq!((changes[0].before.run, changes[0].after.run), (2, 3));
+        assert_eq!(changes[0].percent(), -50.0);
+
+        let changes = compare_in(&root, Some("base")).unwrap();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].before.run, 1);
+        assert_eq!(changes[0].percent(), 50.0);
+
+        assert!(compare_in(&root, Some("missing")).is_err());
+        fs::remove_dir_all(&root).unwrap();
+        assert!(compare_in(&root, None).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,25 @@
+//! Advent of Code solutions and the machinery to run them.
+//!
+//! Each event's solutions live in a `yYYYY` module (`src/yYYYY/dayNN.rs`),
+//! collected into the registry in [`days`]. The shared types (`Day`,
+//! `Solution`, `InputSource`, ...) and parsing helpers are in [`etc`], and
+//! [`runner`] loads, parses and solves a day with timings and answer
+//! verification. The `advent_of_code_template` binary is a thin command line
+//! over this crate.
+
+pub mod bench;
+pub mod cli;
+pub mod config;
+pub mod days;
+pub mod etc;
+pub mod history;
+pub mod memory;
+pub mod report;
+pub mod runner;
+
+pub use etc::{
+    Answers, AocError, BigInt, BigUint, Day, InputSource, Part, Solution, Solver, Verdict,
+};
+
+// `build.rs` declares a `yYYYY` module for every `src/yYYYY/` directory.
+include!(concat!(env!("OUT_DIR"), "/years.rs"));
@@ -1,78 +1,212 @@
-mod days;
-mod etc;
+mod scaffold;
 
-use days::{
-    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-    day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25,
-};
-use etc::solution::Solution;
+use advent_of_code_template::cli::{self, Command, CompareArgs, Format, RunArgs};
+use advent_of_code_template::config::{self, Config};
+use advent_of_code_template::{bench, days, history, report, runner};
 use std::env;
-use std::time::Instant;
+use std::process;
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::EnvFilter;
 
-pub type SolutionPair = (Solution, Solution);
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: advent_of_code_template::memory::CountingAlloc =
+    advent_of_code_template::memory::CountingAlloc;
 
 fn main() {
-    tracing_subscriber::fmt::init();
-    let args: Vec<String> = env::args().collect();
-    if args.len() < 2 {
-        panic!("Please provide the day(s) to run as a command-line argument.");
+    let config = match Config::load() {
+        Ok(config) => config::init(config),
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(2);
+        }
+    };
+
+    // Logs go to stderr so they never interleave with json/csv results.
+    // RUST_LOG wins over the config's filter.
+    let filter = EnvFilter::builder().with_default_directive(LevelFilter::INFO.into());
+    let filter = match (env::var_os(EnvFilter::DEFAULT_ENV), &config.log) {
+        (None, Some(log)) => filter.parse_lossy(log),
+        _ => filter.from_env_lossy(),
+    };
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .init();
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    if let Some(i) = args.iter().position(|a| a == "--checked") {
+        args.remove(i);
+        if env::var_os(CHECKED_ENV).is_none() {
+            process::exit(rerun_checked(&args));
+        }
+        tracing::info!("Running with overflow checks");
     }
 
-    let days: Vec<u8> = args[1..]
-        .iter()
-        .map(|x| {
-            x.parse()
-                .unwrap_or_else(|v| panic!("Not a valid day: {}", v))
-        })
-        .collect();
+    let ok = match cli::parse(&args, config) {
+        Ok(Command::List) => {
+            for year in days::years() {
+                println!("{}", year);
+                for solver in days::of_year(year) {
+                    let status = if solver.implemented() {
+                        ""
+                    } else {
+                        " (not implemented)"
+                    };
+                    println!("  Day {:02}: {}{}", solver.number(), solver.title(), status);
+                }
+            }
+            true
+        }
+        Ok(Command::Run(args)) => run(args),
+        Ok(Command::Profiles(args)) => profiles(&args),
+        Ok(Command::Bench(args)) => bench::bench(&args),
+        Ok(Command::Compare(args)) => compare(&args),
+        Ok(Command::New { year, day, title }) => {
+            match scaffold::new_day(config, year, day, title.as_deref()) {
+                Ok(written) => {
+                    for path in written {
+                        println!("Wrote {}", path.display());
+                    }
+                    true
+                }
+                Err(e) => {
+                    eprintln!("{}", e);
+                    false
+                }
+            }
+        }
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, cli::USAGE);
+            process::exit(2);
+        }
+    };
 
-    let mut runtime = 0.0;
+    if !ok {
+        process::exit(1);
+    }
+}
 
-    for day in days {
-        let func = get_day_solver(day);
+/// Set on the rerun started by `--checked` so it doesn't start another.
+const CHECKED_ENV: &str = "AOC_CHECKED";
 
-        let time = Instant::now();
-        let (p1, p2) = func();
-        let elapsed_ms = time.elapsed().as_nanos() as f64 / 1_000_000.0;
+/// Rebuilds the crate under the `checked` profile and runs it with the same
+/// arguments, returning its exit code.
+fn rerun_checked(args: &[String]) -> i32 {
+    let manifest = concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml");
+    let mut cargo = process::Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_owned()));
+    cargo.args([
+        "run",
+        "--quiet",
+        "--profile",
+        "checked",
+        "--manifest-path",
+        manifest,
+    ]);
+    if cfg!(feature = "alloc-stats") {
+        cargo.args(["--features", "alloc-stats"]);
+    }
+    let status = cargo
+        .arg("--")
+        .args(args)
+        .arg("--checked")
+        .env(CHECKED_ENV, "1")
+        .status();
+    match status {
+        Ok(status) => status.code().unwrap_or(1),
+        Err(e) => {
+            eprintln!("Could not run cargo for --checked: {}", e);
+            1
+        }
+    }
+}
 
-        println!("\n=== Day {:02} ===", day);
-        println!("  · Part 1: {}", p1);
-        println!("  · Part 2: {}", p2);
-        println!("  · Elapsed: {:.4} ms", elapsed_ms);
+/// Runs each task once, printing its answers and per-phase timings. Days
+/// run one after another and print as they finish unless `--jobs` asks for
+/// worker threads, in which case one table is printed at the end. JSON and
+/// CSV are always written once every day has finished. Returns false if any
+/// day failed.
+fn run(run: RunArgs) -> bool {
+    let reports = if run.format != Format::Text {
+        let reports = if run.jobs > 1 {
+            runner::run_parallel(&run)
+        } else {
+            run.tasks
+                .iter()
+                .map(|t| runner::run_task(t, &run))
+                .collect()
+        };
+        match run.format {
+            Format::Json => report::print_json(&reports),
+            _ => report::print_csv(&reports),
+        }
+        reports
+    } else if run.jobs > 1 {
+        let reports = runner::run_parallel(&run);
+        report::print_table(&reports);
+        reports
+    } else {
+        run.tasks
+            .iter()
+            .map(|task| {
+                let report = runner::run_task(task, &run);
+                report::print_block(&report);
+                report
+            })
+            .collect()
+    };
 
-        runtime += elapsed_ms;
+    let mut failed = reports.iter().any(|r| r.failed());
+    if run.lock {
+        for report in &reports {
+            if let Err(e) = runner::lock_answers(report, &run.input) {
+                eprintln!(
+                    "Could not lock answers for {} day {}: {}",
+                    report.solver.year(),
+                    report.solver.number(),
+                    e
+                );
+                failed = true;
+            }
+        }
     }
 
-    println!("Total runtime: {:.4} ms", runtime);
+    if run.format == Format::Text {
+        report::print_totals(&reports);
+    }
+    !failed
 }
 
-fn get_day_solver(day: u8) -> fn() -> SolutionPair {
-    match day {
-        1 => day01::solve,
-        2 => day02::solve,
-        3 => day03::solve,
-        4 => day04::solve,
-        5 => day05::solve,
-        6 => day06::solve,
-        7 => day07::solve,
-        8 => day08::solve,
-        9 => day09::solve,
-        10 => day10::solve,
-        11 => day11::solve,
-        12 => day12::solve,
-        13 => day13::solve,
-        14 => day14::solve,
-        15 => day15::solve,
-        16 => day16::solve,
-        17 => day17::solve,
-        18 => day18::solve,
-        19 => day19::solve,
-        20 => day20::solve,
-        21 => day21::solve,
-        22 => day22::solve,
-        23 => day23::solve,
-        24 => day24::solve,
-        25 => day25::solve,
-        _ => unimplemented!(),
+/// Runs each task against every input profile of its year and prints one
+/// matrix per day. Returns false if any profile failed or disagreed with its
+/// stored answers.
+fn profiles(run: &RunArgs) -> bool {
+    match runner::run_profiles(run) {
+        Ok(runs) => report::print_profiles(&runs),
+        Err(e) => {
+            eprintln!("Could not read input profiles: {}", e);
+            false
+        }
     }
 }
+
+/// Prints how the latest bench run moved against the previous run or a
+/// baseline. Returns false if any phase regressed beyond the threshold.
+fn compare(args: &CompareArgs) -> bool {
+    let changes = match history::compare(args.baseline.as_deref()) {
+        Ok(changes) => changes,
+        Err(e) => {
+            eprintln!("Could not read bench history: {}", e);
+            return false;
+        }
+    };
+    if changes.is_empty() {
+        println!("Nothing to compare yet; run `bench` at least twice.");
+        return true;
+    }
+
+    let against = match &args.baseline {
+        Some(name) => format!("baseline `{}`", name),
+        None => "the previous run".to_owned(),
+    };
+    report::print_changes(&changes, &against, args.threshold)
+}
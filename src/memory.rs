@@ -0,0 +1,124 @@
+#[cfg(feature = "alloc-stats")]
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+/// A global allocator that counts allocations made on each thread. It is
+/// only built, and installed, with the `alloc-stats` feature.
+#[cfg(feature = "alloc-stats")]
+pub struct CountingAlloc;
+
+thread_local! {
+    // Const-initialised `Cell`s have no destructor and never allocate, so
+    // they are safe to touch from inside the allocator.
+    static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+    static ALLOCATED: Cell<u64> = const { Cell::new(0) };
+    static LIVE: Cell<i64> = const { Cell::new(0) };
+    static PEAK: Cell<i64> = const { Cell::new(0) };
+}
+
+#[cfg(feature = "alloc-stats")]
+fn record(allocated: usize, freed: usize) {
+    let _ = ALLOCATIONS.try_with(|n| {
+        if allocated > 0 {
+            n.set(n.get() + 1);
+        }
+    });
+    let _ = ALLOCATED.try_with(|n| n.set(n.get() + allocated as u64));
+    let _ = LIVE.try_with(|live| {
+        let now = live.get() + allocated as i64 - freed as i64;
+        live.set(now);
+        let _ = PEAK.try_with(|peak| peak.set(peak.get().max(now)));
+    });
+}
+
+#[cfg(feature = "alloc-stats")]
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record(0, layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            record(new_size, layout.size());
+        }
+        new
+    }
+}
+
+/// Allocation activity on the current thread between two points.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct AllocStats {
+    pub allocations: u64,
+    pub bytes: u64,
+    /// The most bytes live at once, above what was live at the start.
+    pub peak: u64,
+}
+
+/// The counters at the start of a measurement.
+pub struct Snapshot {
+    allocations: u64,
+    allocated: u64,
+    live: i64,
+}
+
+/// Whether this build counts allocations at all.
+pub fn enabled() -> bool {
+    cfg!(feature = "alloc-stats")
+}
+
+/// Starts a measurement on the current thread.
+pub fn snapshot() -> Snapshot {
+    let live = LIVE.get();
+    PEAK.set(live);
+    Snapshot {
+        allocations: ALLOCATIONS.get(),
+        allocated: ALLOCATED.get(),
+        live,
+    }
+}
+
+impl Snapshot {
+    /// Allocation activity since the snapshot, or `None` if allocations
+    /// aren't being counted.
+    pub fn stats(&self) -> Option<AllocStats> {
+        enabled().then(|| AllocStats {
+            allocations: ALLOCATIONS.get() - self.allocations,
+            bytes: ALLOCATED.get() - self.allocated,
+            peak: (PEAK.get() - self.live).max(0) as u64,
+        })
+    }
+}
+
+/// Formats a byte count with a binary unit, e.g. `12.3 KiB`.
+pub fn human_bytes(bytes: u64) -> String {
+    let units = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < units.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", value, units[unit])
+    }
+}
@@ -0,0 +1,593 @@
+use crate::etc::{AocError, Part, Verdict};
+use crate::history::Change;
+use crate::memory::{human_bytes, AllocStats};
+use crate::runner::{ms, DayReport, Failure};
+use std::fmt::Write;
+use std::time::Duration;
+
+/// Prints one day as a `=== Day NN ===` block.
+pub fn print_block(report: &DayReport) {
+    let solver = report.solver;
+    println!(
+        "\n=== {} Day {:02}: {} ===",
+        solver.year(),
+        solver.number(),
+        solver.title()
+    );
+    match &report.parts {
+        Ok(parts) => {
+            for part in parts {
+                match &part.answer {
+                    Ok(answer) if !answer.is_solved() => {
+                        println!("  · Part {}: unsolved", part.part)
+                    }
+                    Ok(answer) => println!(
+                        "  · Part {}: {} ({:.4} ms) [{}]",
+                        part.part,
+                        answer,
+                        ms(part.elapsed),
+                        verdict(&part.verdict)
+                    ),
+                    Err(e) => println!("  · Part {}: {}", part.part, e),
+                }
+            }
+        }
+        Err(e) => println!("  · {}", e),
+    }
+    if report.unsolved() {
+        return;
+    }
+    println!(
+        "  · Load: {:.4} ms, Parse: {:.4} ms",
+        ms(report.load),
+        ms(report.parse)
+    );
+    match report.allocs {
+        Some(allocs) => println!(
+            "  · Elapsed: {:.4} ms, {} allocations, {} allocated, {} peak",
+            ms(report.elapsed()),
+            allocs.allocations,
+            human_bytes(allocs.bytes),
+            human_bytes(allocs.peak)
+        ),
+        None => println!("  · Elapsed: {:.4} ms", ms(report.elapsed())),
+    }
+}
+
+/// Prints every day as one table with a row per part, followed by the full
+/// message of anything that failed.
+pub fn print_table(reports: &[DayReport]) {
+    let header = [
+        "Day",
+        "Title",
+        "Part",
+        "Answer",
+        "Check",
+        "Time (ms)",
+        "Allocs",
+        "Allocated",
+        "Peak",
+    ];
+    let mut rows: Vec<[String; 9]> = vec![];
+    let mut failures = vec![];
+    for report in reports {
+        let solver = report.solver;
+        let day = format!("{}/{:02}", solver.year(), solver.number());
+        let title = solver.title().to_owned();
+        let [allocs, allocated, peak] = match report.allocs {
+            Some(a) => [
+                a.allocations.to_string(),
+                human_bytes(a.bytes),
+                human_bytes(a.peak),
+            ],
+            None => Default::default(),
+        };
+        match &report.parts {
+            Ok(parts) => {
+                for part in parts {
+                    let (answer, check) = match &part.answer {
+                        Ok(answer) if !answer.is_solved() => {
+                            ("-".to_owned(), verdict(&part.verdict))
+                        }
+                        Ok(answer) => (answer.to_string(), verdict(&part.verdict)),
+                        Err(e) => {
+                            failures.push(format!("Day {} part {}: {}", day, part.part, e));
+                            ("-".to_owned(), e.kind().to_owned())
+                        }
+                    };
+                    rows.push([
+                        day.clone(),
+                        title.clone(),
+                        part.part.to_string(),
+                        answer,
+                        check,
+                        if part.solved() {
+                            format!("{:.4}", ms(part.elapsed))
+                        } else {
+                            "-".to_owned()
+                        },
+                        allocs.clone(),
+                        allocated.clone(),
+                        peak.clone(),
+                    ]);
+                }
+            }
+            Err(e) => {
+                failures.push(format!("Day {}: {}", day, e));
+                rows.push([
+                    day,
+                    title,
+                    "-".to_owned(),
+                    "-".to_owned(),
+                    e.kind().to_owned(),
+                    format!("{:.4}", ms(report.elapsed())),
+                    allocs,
+                    allocated,
+                    peak,
+                ]);
+            }
+        }
+    }
+
+    // The allocation columns only mean something with `alloc-stats`.
+    let columns = if reports.iter().any(|r| r.allocs.is_some()) {
+        9
+    } else {
+        6
+    };
+    let mut widths = header.map(str::len);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let line = |cells: [&str; 9]| {
+        let mut out = String::new();
+        for (i, cell) in cells.iter().take(columns).enumerate() {
+            if i < 5 {
+                out.push_str(&format!("{:<w$}  ", cell, w = widths[i]));
+            } else {
+                out.push_str(&format!("{:>w$}  ", cell, w = widths[i]));
+            }
+        }
+        println!("{}", out.trim_end());
+    };
+    println!();
+    line(header);
+    for row in &rows {
+        line(row.each_ref().map(String::as_str));
+    }
+
+    if !failures.is_empty() {
+        println!();
+        for failure in &failures {
+            println!("{}", failure);
+        }
+    }
+}
+
+/// Prints one matrix per day with a row per input profile: each part's
+/// answer, the time taken and whether the answers match the profile's stored
+/// ones. Panics, errors and mismatches are shown in capitals and their full
+/// messages listed after the matrix. A profile without an input for the day
+/// gets a `no input` row and doesn't count as a failure. Returns false if
+/// any profile failed or mismatched.
+pub fn print_profiles(runs: &[(String, DayReport)]) -> bool {
+    if runs.is_empty() {
+        println!("No input profiles found; add them as input/YYYY/<profile>/dayN.txt");
+        return true;
+    }
+
+    let mut ok = true;
+    for day in runs.chunk_by(|(_, a), (_, b)| {
+        a.solver.year() == b.solver.year() && a.solver.number() == b.solver.number()
+    }) {
+        let solver = day[0].1.solver;
+        println!(
+            "\n=== {} Day {:02}: {} ===",
+            solver.year(),
+            solver.number(),
+            solver.title()
+        );
+
+        let parts: Vec<Part> = Part::ALL
+            .into_iter()
+            .filter(|&p| {
+                day.iter()
+                    .any(|(_, r)| r.parts.iter().flatten().any(|pr| pr.part == p))
+            })
+            .collect();
+        let mut header = vec!["Profile".to_owned()];
+        header.extend(parts.iter().map(|p| format!("Part {}", p)));
+        header.extend(["Time (ms)".to_owned(), "Status".to_owned()]);
+
+        let mut rows = vec![];
+        let mut failures = vec![];
+        for (profile, report) in day {
+            let (status, time, failed) = profile_status(report);
+            ok &= !failed;
+            let mut row = vec![profile.clone()];
+            for &part in &parts {
+                let result = report.parts.iter().flatten().find(|p| p.part == part);
+                row.push(match result.map(|p| (&p.answer, &p.verdict)) {
+                    None => "-".to_owned(),
+                    Some((Ok(answer), _)) if !answer.is_solved() => "unsolved".to_owned(),
+                    Some((Ok(answer), Verdict::Fail { expected })) => {
+                        format!("{} (expected {})", answer, expected)
+                    }
+                    Some((Ok(answer), _)) => answer.to_string(),
+                    Some((Err(e), _)) => {
+                        failures.push(format!("{} part {}: {}", profile, part, e));
+                        e.kind().to_owned()
+                    }
+                });
+            }
+            if let Err(e) = &report.parts {
+                if status != "no input" {
+                    failures.push(format!("{}: {}", profile, e));
+                }
+            }
+            row.extend([time, status]);
+            rows.push(row);
+        }
+
+        let mut widths: Vec<usize> = header.iter().map(String::len).collect();
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+        let time_column = header.len() - 2;
+        let line = |cells: &[String]| {
+            let mut out = String::new();
+            for (i, cell) in cells.iter().enumerate() {
+                if i == time_column {
+                    out.push_str(&format!("{:>w$}  ", cell, w = widths[i]));
+                } else {
+                    out.push_str(&format!("{:<w$}  ", cell, w = widths[i]));
+                }
+            }
+            println!("{}", out.trim_end());
+        };
+        line(&header);
+        for row in &rows {
+            line(row);
+        }
+
+        if !failures.is_empty() {
+            println!();
+            for failure in &failures {
+                println!("{}", failure);
+            }
+        }
+    }
+    ok
+}
+
+/// The status and time cells of one profile's row, and whether the row
+/// counts as a failure. Failures and mismatches are in capitals.
+fn profile_status(report: &DayReport) -> (String, String, bool) {
+    let time = format!("{:.4}", ms(report.elapsed()));
+    let parts = match &report.parts {
+        Ok(parts) => parts,
+        Err(Failure::Error(AocError::MissingInput { .. })) => {
+            return ("no input".to_owned(), "-".to_owned(), false)
+        }
+        Err(e) => return (e.kind().to_uppercase(), time, true),
+    };
+    if let Some(Err(e)) = parts.iter().map(|p| &p.answer).find(|a| a.is_err()) {
+        return (e.kind().to_uppercase(), time, true);
+    }
+    if report.unsolved() {
+        return ("unsolved".to_owned(), "-".to_owned(), false);
+    }
+    if parts
+        .iter()
+        .any(|p| matches!(p.verdict, Verdict::Fail { .. }))
+    {
+        return ("MISMATCH".to_owned(), time, true);
+    }
+    let status = if parts.iter().any(|p| p.verdict == Verdict::Unknown) {
+        "unchecked"
+    } else {
+        "pass"
+    };
+    (status.to_owned(), time, false)
+}
+
+/// Prints the `Total runtime` footer with each phase summed over every day
+/// that has something solved, and how many of the parts run are solved.
+pub fn print_totals(reports: &[DayReport]) {
+    let parts = reports.iter().flat_map(|r| r.parts.iter().flatten());
+    let solved = parts.clone().filter(|p| p.solved()).count();
+    println!("Solved {} of {} parts", solved, parts.count());
+
+    let sum = |phase: fn(&DayReport) -> Duration| {
+        reports
+            .iter()
+            .filter(|r| !r.unsolved())
+            .map(phase)
+            .sum::<Duration>()
+    };
+    let load = sum(|r| r.load);
+    let parse = sum(|r| r.parse);
+    let part1 = sum(|r| r.part_elapsed(Part::One));
+    let part2 = sum(|r| r.part_elapsed(Part::Two));
+    println!(
+        "Total runtime: {:.4} ms (load {:.4} ms, parse {:.4} ms, part 1 {:.4} ms, part 2 {:.4} ms)",
+        ms(load + parse + part1 + part2),
+        ms(load),
+        ms(parse),
+        ms(part1),
+        ms(part2)
+    );
+}
+
+fn verdict(verdict: &Verdict) -> String {
+    match verdict {
+        Verdict::Pass => "pass".to_owned(),
+        Verdict::Fail { expected } => format!("FAIL: expected {}", expected),
+        Verdict::Unknown => "unknown".to_owned(),
+        Verdict::Unsolved => "unsolved".to_owned(),
+    }
+}
+
+/// One part of one day, flattened for machine-readable output. Days that
+/// failed before any part ran get a single row without a part.
+struct Row {
+    year: u16,
+    day: u8,
+    title: &'static str,
+    part: Option<Part>,
+    answer: Option<String>,
+    kind: Option<&'static str>,
+    status: &'static str,
+    message: Option<String>,
+    load_ms: f64,
+    parse_ms: f64,
+    part_ms: Option<f64>,
+    allocs: Option<AllocStats>,
+}
+
+fn rows(reports: &[DayReport]) -> Vec<Row> {
+    let mut rows = vec![];
+    for report in reports {
+        let row = |part, status, message| Row {
+            year: report.solver.year(),
+            day: report.solver.number(),
+            title: report.solver.title(),
+            part,
+            answer: None,
+            kind: None,
+            status,
+            message,
+            load_ms: ms(report.load),
+            parse_ms: ms(report.parse),
+            part_ms: None,
+            allocs: report.allocs,
+        };
+        match &report.parts {
+            Ok(parts) => {
+                for part in parts {
+                    rows.push(match &part.answer {
+                        Ok(answer) => {
+                            let (status, message) = match &part.verdict {
+                                Verdict::Pass => ("pass", None),
+                                Verdict::Fail { expected } => {
+                                    ("fail", Some(format!("expected {}", expected)))
+                                }
+                                Verdict::Unknown => ("unknown", None),
+                                Verdict::Unsolved => ("unsolved", None),
+                            };
+                            Row {
+                                answer: answer.is_solved().then(|| answer.to_string()),
+                                kind: Some(answer.kind()),
+                                part_ms: Some(ms(part.elapsed)),
+                                ..row(Some(part.part), status, message)
+                            }
+                        }
+                        Err(e) => Row {
+                            part_ms: Some(ms(part.elapsed)),
+                            ..row(Some(part.part), e.kind(), Some(e.to_string()))
+                        },
+                    });
+                }
+            }
+            Err(e) => rows.push(row(None, e.kind(), Some(e.to_string()))),
+        }
+    }
+    rows
+}
+
+/// Prints every row as one JSON array of objects.
+pub fn print_json(reports: &[DayReport]) {
+    let str_or_null = |s: Option<&str>| s.map_or_else(|| "null".to_owned(), json_string);
+    let num_or_null = |n: Option<f64>| n.map_or_else(|| "null".to_owned(), |n| n.to_string());
+    let alloc_or_null = |n: Option<u64>| n.map_or_else(|| "null".to_owned(), |n| n.to_string());
+
+    let mut out = String::from("[");
+    for (i, row) in rows(reports).iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        write!(
+            out,
+            "\n  {{\"year\": {}, \"day\": {}, \"title\": {}, \"part\": {}, \"answer\": {}, \"type\": {}, \
+             \"status\": {}, \"message\": {}, \"load_ms\": {}, \"parse_ms\": {}, \"part_ms\": {}, \
+             \"allocations\": {}, \"allocated_bytes\": {}, \"peak_bytes\": {}}}",
+            row.year,
+            row.day,
+            json_string(row.title),
+            row.part
+                .map_or_else(|| "null".to_owned(), |p| p.to_string()),
+            str_or_null(row.answer.as_deref()),
+            str_or_null(row.kind),
+            json_string(row.status),
+            str_or_null(row.message.as_deref()),
+            row.load_ms,
+            row.parse_ms,
+            num_or_null(row.part_ms),
+            alloc_or_null(row.allocs.map(|a| a.allocations)),
+            alloc_or_null(row.allocs.map(|a| a.bytes)),
+            alloc_or_null(row.allocs.map(|a| a.peak)),
+        )
+        .unwrap();
+    }
+    out.push_str("\n]");
+    println!("{}", out);
+}
+
+/// Prints every row as CSV with a header line.
+pub fn print_csv(reports: &[DayReport]) {
+    println!(
+        "year,day,title,part,answer,type,status,message,load_ms,parse_ms,part_ms,\
+         allocations,allocated_bytes,peak_bytes"
+    );
+    for row in rows(reports) {
+        let fields = [
+            row.year.to_string(),
+            row.day.to_string(),
+            row.title.to_owned(),
+            row.part.map(|p| p.to_string()).unwrap_or_default(),
+            row.answer.unwrap_or_default(),
+            row.kind.unwrap_or_default().to_owned(),
+            row.status.to_owned(),
+            row.message.unwrap_or_default(),
+            row.load_ms.to_string(),
+            row.parse_ms.to_string(),
+            row.part_ms.map(|n| n.to_string()).unwrap_or_default(),
+            row.allocs
+                .map(|a| a.allocations.to_string())
+                .unwrap_or_default(),
+            row.allocs.map(|a| a.bytes.to_string()).unwrap_or_default(),
+            row.allocs.map(|a| a.peak.to_string()).unwrap_or_default(),
+        ];
+        let fields: Vec<String> = fields.iter().map(|f| csv_field(f)).collect();
+        println!("{}", fields.join(","));
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_owned()
+    }
+}
+
+/// Prints how each bench phase moved between two runs, flagging changes
+/// beyond `threshold` percent. Returns false if any phase regressed.
+pub fn print_changes(changes: &[Change], against: &str, threshold: f64) -> bool {
+    let latest = &changes[0].after;
+    println!(
+        "Comparing run {} ({}) against {}",
+        latest.run,
+        if latest.commit.is_empty() {
+            "no commit"
+        } else {
+            &latest.commit
+        },
+        against
+    );
+    println!(
+        "{:<7} {:<6} {:>12} {:>12} {:>9}",
+        "Day", "Phase", "Before (ms)", "After (ms)", "Change"
+    );
+
+    let mut regressed = false;
+    for change in changes {
+        let percent = change.percent();
+        let flag = if percent > threshold {
+            regressed = true;
+            "  REGRESSED"
+        } else if percent < -threshold {
+            "  improved"
+        } else {
+            ""
+        };
+        println!(
+            "{:<7} {:<6} {:>12.4} {:>12.4} {:>+8.1}%{}",
+            format!("{}/{:02}", change.year, change.day),
+            change.phase,
+            change.before.median_ns as f64 / 1_000_000.0,
+            change.after.median_ns as f64 / 1_000_000.0,
+            percent,
+            flag
+        );
+    }
+    !regressed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escaping() {
+        assert_eq!(json_string("a\"b\\c\n"), r#""a\"b\\c\n""#);
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("x,y"), "\"x,y\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn test_profile_status() {
+        use crate::days;
+        use crate::runner::PartReport;
+        use crate::Solution;
+        use std::io;
+
+        let report = |parts| DayReport {
+            solver: days::get(2024, 1).unwrap(),
+            load: Duration::ZERO,
+            parse: Duration::ZERO,
+            parts,
+            allocs: None,
+        };
+        let part = |verdict| PartReport {
+            part: Part::One,
+            answer: Ok(Solution::from(11u64)),
+            elapsed: Duration::ZERO,
+            verdict,
+        };
+
+        let missing = report(Err(Failure::Error(AocError::MissingInput {
+            path: "day1.txt".into(),
+            source: io::ErrorKind::NotFound.into(),
+        })));
+        assert_eq!(profile_status(&missing).0, "no input");
+        assert!(!profile_status(&missing).2);
+
+        let passed = report(Ok(vec![part(Verdict::Pass)]));
+        assert_eq!(profile_status(&passed).0, "pass");
+
+        let expected = Solution::from(12u64);
+        let mismatched = report(Ok(vec![part(Verdict::Fail { expected })]));
+        assert_eq!(profile_status(&mismatched).0, "MISMATCH");
+        assert!(profile_status(&mismatched).2);
+
+        let panicked = report(Err(Failure::Panic {
+            message: "boom".to_owned(),
+            location: "src/y2024/day01.rs:1:1".to_owned(),
+        }));
+        assert_eq!(profile_status(&panicked).0, "PANICKED");
+    }
+}
@@ -0,0 +1,108 @@
+use crate::etc::AocError;
+use std::cell::{Cell, RefCell};
+use std::fmt::{self, Display, Formatter};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+use std::time::Duration;
+
+/// Why a day or part produced no answer.
+#[derive(Debug)]
+pub enum Failure {
+    Error(AocError),
+    Panic { message: String, location: String },
+    Timeout(Duration),
+}
+
+impl Failure {
+    /// A one-word summary, for places too narrow for the full message.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Failure::Error(_) => "error",
+            Failure::Panic { .. } => "panicked",
+            Failure::Timeout(_) => "timed out",
+        }
+    }
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::Error(e) => write!(f, "error: {}", e),
+            Failure::Panic { message, location } => {
+                write!(f, "panicked at {}: {}", location, message)
+            }
+            Failure::Timeout(limit) => write!(f, "timed out after {}s", limit.as_secs_f64()),
+        }
+    }
+}
+
+impl From<AocError> for Failure {
+    fn from(e: AocError) -> Self {
+        Failure::Error(e)
+    }
+}
+
+thread_local! {
+    static GUARDED: Cell<bool> = const { Cell::new(false) };
+    static CAUGHT: RefCell<Option<Failure>> = const { RefCell::new(None) };
+}
+
+/// Installs a panic hook that records panics raised inside [`guarded`]
+/// instead of printing them, and defers to the previous hook otherwise.
+fn install_hook() {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let default = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !GUARDED.get() {
+                return default(info);
+            }
+            let message = match info.payload().downcast_ref::<&str>() {
+                Some(s) => s.to_string(),
+                None => match info.payload().downcast_ref::<String>() {
+                    Some(s) => s.clone(),
+                    None => "Box<dyn Any>".to_owned(),
+                },
+            };
+            let location = info
+                .location()
+                .map_or_else(|| "<unknown>".to_owned(), |l| l.to_string());
+            CAUGHT.set(Some(Failure::Panic { message, location }));
+        }));
+    });
+}
+
+/// Runs `f`, turning a panic into a [`Failure::Panic`] so the caller can
+/// carry on with the next day.
+pub fn guarded<T>(f: impl FnOnce() -> Result<T, AocError>) -> Result<T, Failure> {
+    install_hook();
+    GUARDED.set(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    GUARDED.set(false);
+
+    match result {
+        Ok(result) => result.map_err(Failure::Error),
+        Err(_) => Err(CAUGHT.take().unwrap_or_else(|| Failure::Panic {
+            message: "unknown panic".to_owned(),
+            location: "<unknown>".to_owned(),
+        })),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_guarded_panic() {
+        let result: Result<(), Failure> = guarded(|| panic!("no valid direction"));
+        match result {
+            Err(Failure::Panic { message, location }) => {
+                assert_eq!(message, "no valid direction");
+                assert!(location.starts_with("src/runner/guard.rs:"));
+            }
+            _ => panic!("expected a caught panic"),
+        }
+        assert!(matches!(guarded(|| Ok(1)), Ok(1)));
+    }
+}
@@ -0,0 +1,284 @@
+mod guard;
+mod watchdog;
+
+pub use guard::{guarded, Failure};
+pub use watchdog::run_day_with_timeout;
+
+use crate::cli::{RunArgs, Task};
+use crate::etc::{Answers, AocError, InputSource, Part, Solution, Solver, Verdict};
+use crate::memory::{self, AllocStats};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// The answer to one part of a day and how long it took.
+pub struct PartReport {
+    pub part: Part,
+    pub answer: Result<Solution, Failure>,
+    pub elapsed: Duration,
+    pub verdict: Verdict,
+}
+
+/// Everything the runner learned from running one day once.
+pub struct DayReport {
+    pub solver: &'static dyn Solver,
+    pub load: Duration,
+    pub parse: Duration,
+    /// The per-part results, or the error that stopped the day before any
+    /// part could run.
+    pub parts: Result<Vec<PartReport>, Failure>,
+    /// Allocations made while running the day, when built with `alloc-stats`.
+    pub allocs: Option<AllocStats>,
+}
+
+impl PartReport {
+    /// Whether the part produced a real answer, as opposed to failing or
+    /// returning `Solution::Unsolved`.
+    pub fn solved(&self) -> bool {
+        self.answer.as_ref().is_ok_and(Solution::is_solved)
+    }
+
+    fn unsolved(&self) -> bool {
+        matches!(self.answer, Ok(Solution::Unsolved))
+    }
+}
+
+impl DayReport {
+    /// Time spent on the day, leaving out parts that are still unsolved.
+    pub fn elapsed(&self) -> Duration {
+        let parts = self
+            .parts
+            .iter()
+            .flatten()
+            .filter(|p| !p.unsolved())
+            .map(|p| p.elapsed)
+            .sum::<Duration>();
+        self.load + self.parse + parts
+    }
+
+    pub fn part_elapsed(&self, part: Part) -> Duration {
+        self.parts
+            .iter()
+            .flatten()
+            .filter(|p| p.part == part && !p.unsolved())
+            .map(|p| p.elapsed)
+            .sum()
+    }
+
+    /// Whether every part that ran returned `Solution::Unsolved`.
+    pub fn unsolved(&self) -> bool {
+        self.parts
+            .as_ref()
+            .is_ok_and(|parts| !parts.is_empty() && parts.iter().all(PartReport::unsolved))
+    }
+
+    pub fn failed(&self) -> bool {
+        match &self.parts {
+            Ok(parts) => parts
+                .iter()
+                .any(|p| p.answer.is_err() || matches!(p.verdict, Verdict::Fail { .. })),
+            Err(_) => true,
+        }
+    }
+}
+
+/// A step of a day's run, emitted as soon as it completes so a watchdog can
+/// keep whatever finished before a timeout.
+pub enum Progress {
+    Loaded(Duration),
+    Parsed(Duration),
+    Solved(PartReport),
+    Failed(Failure),
+    Allocated(AllocStats),
+}
+
+impl DayReport {
+    fn new(solver: &'static dyn Solver) -> Self {
+        DayReport {
+            solver,
+            load: Duration::ZERO,
+            parse: Duration::ZERO,
+            parts: Ok(vec![]),
+            allocs: None,
+        }
+    }
+
+    fn apply(&mut self, progress: Progress) {
+        match progress {
+            Progress::Loaded(elapsed) => self.load = elapsed,
+            Progress::Parsed(elapsed) => self.parse = elapsed,
+            Progress::Solved(part) => {
+                if let Ok(parts) = &mut self.parts {
+                    parts.push(part);
+                }
+            }
+            Progress::Failed(failure) => self.parts = Err(failure),
+            Progress::Allocated(stats) => self.allocs = Some(stats),
+        }
+    }
+}
+
+/// Loads, parses and solves the selected parts of one day, timing each phase.
+/// Answers to the puzzle input are checked against the answers store. Panics
+/// in the solver are caught and reported as failures.
+pub fn run_day(task: &Task, source: &InputSource) -> DayReport {
+    let mut report = DayReport::new(task.solver);
+    execute(task, source, |progress| report.apply(progress));
+    report
+}
+
+/// Runs one task with the options from `args`, under the watchdog if a
+/// timeout was given.
+pub fn run_task(task: &Task, args: &RunArgs) -> DayReport {
+    run_from(task, &args.input, args.timeout)
+}
+
+/// Runs one task against `source`, under the watchdog if there is a
+/// `timeout`.
+pub fn run_from(task: &Task, source: &InputSource, timeout: Option<Duration>) -> DayReport {
+    match timeout {
+        Some(limit) => run_day_with_timeout(task, source, limit),
+        None => run_day(task, source),
+    }
+}
+
+/// Runs every task on `args.jobs` worker threads, returning the reports in
+/// task order.
+pub fn run_parallel(args: &RunArgs) -> Vec<DayReport> {
+    parallel(&args.tasks, args.jobs, |task| run_task(task, args))
+}
+
+/// Runs every task against every input profile of its year, on `args.jobs`
+/// worker threads. Returns each profile's name with its report, grouped by
+/// task in task order and by profile in name order.
+pub fn run_profiles(args: &RunArgs) -> Result<Vec<(String, DayReport)>, AocError> {
+    let mut runs = vec![];
+    let mut profiles: Vec<(u16, Vec<String>)> = vec![];
+    for task in &args.tasks {
+        let year = task.solver.year();
+        if !profiles.iter().any(|(y, _)| *y == year) {
+            profiles.push((year, InputSource::profiles(year)?));
+        }
+        let (_, names) = profiles.iter().find(|(y, _)| *y == year).unwrap();
+        runs.extend(names.iter().map(|name| (task, name.clone())));
+    }
+
+    let reports = parallel(&runs, args.jobs, |(task, name)| {
+        run_from(task, &InputSource::Profile(name.clone()), args.timeout)
+    });
+    Ok(runs
+        .into_iter()
+        .map(|(_, name)| name)
+        .zip(reports)
+        .collect())
+}
+
+/// Calls `run` on every item from `jobs` worker threads, returning the
+/// reports in item order.
+fn parallel<T: Sync>(
+    items: &[T],
+    jobs: usize,
+    run: impl Fn(&T) -> DayReport + Sync,
+) -> Vec<DayReport> {
+    let next = AtomicUsize::new(0);
+    let reports: Mutex<Vec<Option<DayReport>>> = Mutex::new(items.iter().map(|_| None).collect());
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(items.len()) {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(i) else {
+                    break;
+                };
+                let report = run(item);
+                reports.lock().unwrap()[i] = Some(report);
+            });
+        }
+    });
+
+    reports
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|report| report.expect("every item was run"))
+        .collect()
+}
+
+fn execute(task: &Task, source: &InputSource, mut emit: impl FnMut(Progress)) {
+    let start = memory::snapshot();
+    execute_phases(task, source, &mut emit);
+    if let Some(stats) = start.stats() {
+        emit(Progress::Allocated(stats));
+    }
+}
+
+fn execute_phases(task: &Task, source: &InputSource, mut emit: impl FnMut(Progress)) {
+    let solver = task.solver;
+
+    let time = Instant::now();
+    let content = source.load(solver.year(), solver.number());
+    emit(Progress::Loaded(time.elapsed()));
+    let content = match content {
+        Ok(content) => content,
+        Err(e) => return emit(Progress::Failed(e.into())),
+    };
+
+    let answers = match source.answers_path(solver.year(), solver.number()) {
+        Some(path) => Answers::read(path),
+        None => Ok(Answers::default()),
+    };
+    let answers = match answers {
+        Ok(answers) => answers,
+        Err(e) => return emit(Progress::Failed(e.into())),
+    };
+
+    let time = Instant::now();
+    let input = guarded(|| solver.parse(&content));
+    emit(Progress::Parsed(time.elapsed()));
+    let input = match input {
+        Ok(input) => input,
+        Err(e) => return emit(Progress::Failed(e)),
+    };
+
+    for &part in &task.parts {
+        let time = Instant::now();
+        let answer = guarded(|| solver.solve(part, &*input));
+        let elapsed = time.elapsed();
+        let verdict = match &answer {
+            Ok(answer) => answers.check(part, answer),
+            Err(_) => Verdict::Unknown,
+        };
+        emit(Progress::Solved(PartReport {
+            part,
+            answer,
+            elapsed,
+            verdict,
+        }));
+    }
+}
+
+/// Stores every answer in `report` that has nothing locked in yet, next to
+/// the answers for `source`.
+pub fn lock_answers(report: &DayReport, source: &InputSource) -> Result<(), AocError> {
+    let (year, day) = (report.solver.year(), report.solver.number());
+    let Some(path) = source.answers_path(year, day) else {
+        return Ok(());
+    };
+    let mut answers = Answers::read(path.clone())?;
+    let mut changed = false;
+    for part in report.parts.iter().flatten() {
+        if let (Ok(answer), Verdict::Unknown) = (&part.answer, &part.verdict) {
+            answers.set(part.part, answer.clone());
+            changed = true;
+        }
+    }
+    if changed {
+        answers.write(&path)?;
+    }
+    Ok(())
+}
+
+pub fn ms(duration: Duration) -> f64 {
+    duration.as_nanos() as f64 / 1_000_000.0
+}
@@ -0,0 +1,124 @@
+use super::{execute, DayReport, Failure, PartReport, Progress};
+use crate::cli::Task;
+use crate::etc::{InputSource, Verdict};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Runs a day on its own thread and gives up on it once `limit` has passed.
+///
+/// Threads can't be killed, so a day that times out keeps spinning in the
+/// background until the process exits; anything it finished before the
+/// deadline is still reported.
+pub fn run_day_with_timeout(task: &Task, source: &InputSource, limit: Duration) -> DayReport {
+    let mut report = DayReport::new(task.solver);
+    let (tx, rx) = mpsc::channel();
+
+    let worker_task = task.clone();
+    let worker_source = source.clone();
+    thread::Builder::new()
+        .name(format!(
+            "y{}-day{:02}",
+            task.solver.year(),
+            task.solver.number()
+        ))
+        .spawn(move || {
+            execute(&worker_task, &worker_source, |progress| {
+                // The receiver is gone once the day has timed out.
+                let _ = tx.send(progress);
+            })
+        })
+        .expect("could not spawn a thread for the day");
+
+    let deadline = Instant::now() + limit;
+    let mut stage = Stage::Loading;
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        match rx.recv_timeout(remaining) {
+            Ok(progress) => {
+                match progress {
+                    Progress::Loaded(_) => stage = Stage::Parsing,
+                    Progress::Parsed(_) => stage = Stage::Solving,
+                    _ => {}
+                }
+                report.apply(progress);
+            }
+            Err(RecvTimeoutError::Disconnected) => break,
+            Err(RecvTimeoutError::Timeout) => {
+                time_out(&mut report, task, stage, limit);
+                break;
+            }
+        }
+    }
+    report
+}
+
+/// The phase a day's worker is in, as far as its progress messages tell.
+#[derive(Clone, Copy)]
+enum Stage {
+    Loading,
+    Parsing,
+    Solving,
+}
+
+/// Marks whichever phase was running at the deadline as timed out. A day
+/// that already failed keeps its failure, and one whose parts have all
+/// reported only had cleanup left, so it is complete.
+fn time_out(report: &mut DayReport, task: &Task, stage: Stage, limit: Duration) {
+    let spent = report.elapsed();
+    let Ok(parts) = &mut report.parts else {
+        return;
+    };
+    match stage {
+        Stage::Solving => {
+            if let Some(&part) = task.parts.get(parts.len()) {
+                parts.push(PartReport {
+                    part,
+                    answer: Err(Failure::Timeout(limit)),
+                    elapsed: limit.saturating_sub(spent),
+                    verdict: Verdict::Unknown,
+                });
+            }
+        }
+        Stage::Loading => {
+            report.load = limit.saturating_sub(spent);
+            report.parts = Err(Failure::Timeout(limit));
+        }
+        Stage::Parsing => {
+            report.parse = limit.saturating_sub(spent);
+            report.parts = Err(Failure::Timeout(limit));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+    use crate::etc::{Part, Solution};
+
+    #[test]
+    fn test_time_out() {
+        let task = Task {
+            solver: days::get(2024, 1).unwrap(),
+            parts: vec![Part::One],
+        };
+        let limit = Duration::from_secs(1);
+
+        // Every part reported; only cleanup was left at the deadline.
+        let mut report = DayReport::new(task.solver);
+        report.apply(Progress::Solved(PartReport {
+            part: Part::One,
+            answer: Ok(Solution::from(11u64)),
+            elapsed: Duration::ZERO,
+            verdict: Verdict::Unknown,
+        }));
+        time_out(&mut report, &task, Stage::Solving, limit);
+        assert!(!report.failed());
+
+        let mut report = DayReport::new(task.solver);
+        time_out(&mut report, &task, Stage::Loading, limit);
+        assert_eq!((report.load, report.parse), (limit, Duration::ZERO));
+        assert!(matches!(report.parts, Err(Failure::Timeout(_))));
+    }
+}
@@ -0,0 +1,123 @@
+use advent_of_code_template::config::Config;
+use advent_of_code_template::AocError;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+const TEMPLATE: &str = include_str!("../templates/day.rs");
+
+/// Marks a day file that is still a stub and may be regenerated.
+const STUB_MARKER: &str = "const IMPLEMENTED: bool = false;";
+
+/// Generates `src/yYYYY/dayNN.rs` under `config.root` from the template, starting the year's
+/// directory if need be, and creates empty input and example answer files;
+/// `build.rs` picks the new file up on the next build. Existing stubs are
+/// replaced, but a file that no longer carries the stub marker is left
+/// alone. Returns the files that were written.
+pub fn new_day(
+    config: &Config,
+    year: u16,
+    day: u8,
+    title: Option<&str>,
+) -> Result<Vec<PathBuf>, AocError> {
+    let src = config.root.join("src");
+    if !src.is_dir() {
+        return Err(AocError::Io {
+            path: src,
+            source: io::Error::new(
+                io::ErrorKind::NotFound,
+                "run this inside the repository or set AOC_CONFIG",
+            ),
+        });
+    }
+    let days_dir = src.join(format!("y{}", year));
+    fs::create_dir_all(&days_dir).map_err(AocError::io(&days_dir))?;
+
+    let path = days_dir.join(format!("day{:02}.rs", day));
+    let existing = fs::read_to_string(&path).ok();
+    if let Some(existing) = &existing {
+        if !existing.contains(STUB_MARKER) {
+            return Err(AocError::AlreadySolved(path));
+        }
+    }
+
+    let title = match (title, &existing) {
+        (Some(title), _) => title.to_owned(),
+        (None, Some(existing)) => {
+            existing_title(existing).unwrap_or_else(|| format!("Day {}", day))
+        }
+        (None, None) => format!("Day {}", day),
+    };
+    let source = TEMPLATE
+        .replace("{{YEAR}}", &year.to_string())
+        .replace("{{NN}}", &format!("{:02}", day))
+        .replace("{{N}}", &day.to_string())
+        .replace(
+            "{{TITLE}}",
+            &title.replace('\\', "\\\\").replace('"', "\\\""),
+        );
+
+    let mut written = vec![];
+    fs::write(&path, source).map_err(AocError::io(&path))?;
+    written.push(path);
+
+    for input in [
+        format!("day{}.txt", day),
+        format!("day{}_short.txt", day),
+        format!("day{}_short.answers", day),
+    ] {
+        let input = config.input_dir().join(year.to_string()).join(input);
+        if !input.exists() {
+            if let Some(dir) = input.parent() {
+                fs::create_dir_all(dir).map_err(AocError::io(dir))?;
+            }
+            fs::write(&input, "").map_err(AocError::io(&input))?;
+            written.push(input);
+        }
+    }
+
+    Ok(written)
+}
+
+fn existing_title(source: &str) -> Option<String> {
+    let re = regex::Regex::new(r#"const TITLE: &'static str = "(.*)";"#).unwrap();
+    re.captures(source).map(|c| c[1].to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_new_day() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src")).unwrap();
+        let config = Config {
+            root: root.clone(),
+            ..Config::default()
+        };
+
+        let written = new_day(&config, 2015, 3, Some("Perfectly Spherical Houses")).unwrap();
+        let path = root.join("src/y2015/day03.rs");
+        assert_eq!(written[0], path);
+        assert!(root.join("input/2015/day3_short.answers").is_file());
+        let stub = fs::read_to_string(&path).unwrap();
+        assert!(stub.contains("pub struct Day03;"));
+
+        // A stub is regenerated and keeps its title.
+        new_day(&config, 2015, 3, None).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), stub);
+
+        // A solved day is left alone.
+        let solved = stub.replace(STUB_MARKER, "");
+        fs::write(&path, &solved).unwrap();
+        assert!(matches!(
+            new_day(&config, 2015, 3, None),
+            Err(AocError::AlreadySolved(p)) if p == path
+        ));
+        assert_eq!(fs::read_to_string(&path).unwrap(), solved);
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
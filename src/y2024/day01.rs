@@ -0,0 +1,56 @@
+use crate::etc::parse::{fields, number};
+use crate::etc::{AocError, Day};
+use crate::Solution;
+///////////////////////////////////////////////////////////////////////////////
+
+pub struct Day01;
+
+impl Day for Day01 {
+    const YEAR: u16 = 2024;
+    const NUMBER: u8 = 1;
+    const TITLE: &'static str = "Historian Hysteria";
+
+    type Input = (Vec<i32>, Vec<i32>);
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let mut lhs = vec![];
+        let mut rhs = vec![];
+        for (n, l) in input.lines().enumerate() {
+            let line = n + 1;
+            if l.trim().is_empty() {
+                continue;
+            }
+            let mut ii = fields(l);
+            let (a, b) = match (ii.next(), ii.next(), ii.next()) {
+                (Some(a), Some(b), None) => (a, b),
+                _ => return Err(AocError::parse(line, 1, "expected two numbers")),
+            };
+            lhs.push(number(a.1, line, a.0)?);
+            rhs.push(number(b.1, line, b.0)?);
+        }
+
+        lhs.sort();
+        rhs.sort();
+        Ok((lhs, rhs))
+    }
+
+    fn part1((lhs, rhs): &Self::Input) -> Result<Solution, AocError> {
+        let mut d = 0u64;
+        for (ctr, &lhv) in lhs.iter().enumerate() {
+            let rhv = rhs[ctr];
+            d += (i64::from(rhv) - i64::from(lhv)).unsigned_abs();
+        }
+
+        Ok(Solution::from(d))
+    }
+
+    fn part2((lhs, rhs): &Self::Input) -> Result<Solution, AocError> {
+        let mut simscore = 0i64;
+        for lhv in lhs {
+            let count = rhs.iter().filter(|x| x == &lhv).count();
+            simscore += count as i64 * i64::from(*lhv);
+        }
+
+        Ok(Solution::from(simscore))
+    }
+}
@@ -0,0 +1,91 @@
+use crate::etc::parse::{fields, number};
+use crate::etc::{AocError, Day};
+use crate::Solution;
+use std::cmp;
+
+///////////////////////////////////////////////////////////////////////////////
+
+fn is_report_safe(report: &[i32]) -> bool {
+    let mut min_diff = i32::MAX;
+    let mut max_diff = i32::MIN;
+    let mut all_positive = true;
+    let mut all_negative = true;
+
+    for window in report.windows(2) {
+        let diff = window[1] - window[0];
+        min_diff = cmp::min(min_diff, diff);
+        max_diff = cmp::max(max_diff, diff);
+        all_positive &= diff > 0;
+        all_negative &= diff < 0;
+
+        // Early exit if unsafe
+        if max_diff > 3 || min_diff < -3 || (!all_positive && !all_negative) {
+            return false;
+        }
+    }
+
+    true
+}
+
+pub struct Day02;
+
+impl Day for Day02 {
+    const YEAR: u16 = 2024;
+    const NUMBER: u8 = 2;
+    const TITLE: &'static str = "Red-Nosed Reports";
+
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(n, line)| {
+                fields(line)
+                    .map(|(column, x)| number(x, n + 1, column))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part1(reports: &Self::Input) -> Result<Solution, AocError> {
+        let safe_reports = reports.iter().filter(|r| is_report_safe(r)).count();
+
+        Ok(Solution::from(safe_reports as u64))
+    }
+
+    fn part2(reports: &Self::Input) -> Result<Solution, AocError> {
+        let safe_damp = reports.iter().filter(|r| is_dampened_safe(r)).count();
+
+        Ok(Solution::from(safe_damp as u64))
+    }
+}
+
+fn is_dampened_safe(report: &[i32]) -> bool {
+    if is_report_safe(report) {
+        return true;
+    }
+
+    // Check all possible combinations with one element removed.
+    for i in 0..report.len() {
+        if i > 0 && report[i] == report[i - 1] {
+            // Skip duplicates for performance
+            continue;
+        }
+
+        // Check safety without creating a new vector
+        let is_safe = is_report_safe(
+            &[
+                &report[..i],     // Elements before the removed one
+                &report[i + 1..], // Elements after the removed one
+            ]
+            .concat(),
+        );
+        if is_safe {
+            return true;
+        }
+    }
+
+    false
+}
@@ -0,0 +1,73 @@
+use crate::etc::parse::{number, position};
+use crate::etc::{AocError, Day};
+use crate::Solution;
+
+///////////////////////////////////////////////////////////////////////////////
+pub enum Instruction {
+    Mul(u128),
+    Do,
+    Dont,
+}
+
+fn multiply_str(inp: &str, line: usize, column: usize) -> Result<u128, AocError> {
+    let (a, b) = inp[4..inp.len() - 1] // Strip "mul(" and ")"
+        .split_once(',')
+        .ok_or_else(|| AocError::parse(line, column, "expected two operands"))?;
+    // Two u64 operands always multiply within a u128.
+    let a: u64 = number(a, line, column)?;
+    let b: u64 = number(b, line, column)?;
+    Ok(u128::from(a) * u128::from(b))
+}
+
+pub struct Day03;
+
+impl Day for Day03 {
+    const YEAR: u16 = 2024;
+    const NUMBER: u8 = 3;
+    const TITLE: &'static str = "Mull It Over";
+
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let re = regex::Regex::new(r"mul\(\d+,\d+\)|do\(\)|don't\(\)").unwrap();
+        re.find_iter(input)
+            .map(|mat| match mat.as_str() {
+                "do()" => Ok(Instruction::Do),
+                "don't()" => Ok(Instruction::Dont),
+                matched => {
+                    let (line, column) = position(input, mat.start());
+                    Ok(Instruction::Mul(multiply_str(matched, line, column)?))
+                }
+            })
+            .collect()
+    }
+
+    fn part1(instructions: &Self::Input) -> Result<Solution, AocError> {
+        let mut total = 0u128;
+        for instruction in instructions {
+            if let Instruction::Mul(mul_val) = instruction {
+                total += mul_val;
+            }
+        }
+
+        Ok(Solution::from(total))
+    }
+
+    fn part2(instructions: &Self::Input) -> Result<Solution, AocError> {
+        let mut total2 = 0u128;
+        let mut active = true;
+        for instruction in instructions {
+            match instruction {
+                Instruction::Mul(mul_val) => {
+                    if active {
+                        total2 += mul_val;
+                    }
+                }
+                Instruction::Do => active = true,
+                Instruction::Dont => active = false,
+            }
+        }
+
+        Ok(Solution::from(total2))
+    }
+}
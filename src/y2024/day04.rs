@@ -0,0 +1,172 @@
+use crate::etc::grid::parse_char_grid;
+use crate::etc::{AocError, Day};
+use crate::Solution;
+use tracing::debug;
+
+///////////////////////////////////////////////////////////////////////////////
+
+pub struct Grid {
+    cols: usize,
+    data: Vec<char>,
+}
+
+#[derive(Debug)]
+pub enum Directions {
+    N,
+    S,
+    E,
+    W,
+    NE,
+    NW,
+    SE,
+    SW,
+}
+
+impl Directions {
+    pub fn all() -> &'static [Directions] {
+        &[
+            Directions::N,
+            Directions::S,
+            Directions::E,
+            Directions::W,
+            Directions::NE,
+            Directions::NW,
+            Directions::SE,
+            Directions::SW,
+        ]
+    }
+}
+
+impl Grid {
+    pub fn new(input: &str) -> Result<Self, AocError> {
+        let (cols, rows, data) = parse_char_grid(input)?;
+        debug!("Grid dimensions: cols={}, rows={}", cols, rows);
+        Ok(Self { cols, data })
+    }
+
+    /// The `(row, column)` change of one step in `direction`.
+    pub fn direction_step(&self, direction: &Directions) -> (isize, isize) {
+        match direction {
+            Directions::N => (-1, 0),
+            Directions::S => (1, 0),
+            Directions::E => (0, 1),
+            Directions::W => (0, -1),
+            Directions::NE => (-1, 1),
+            Directions::NW => (-1, -1),
+            Directions::SE => (1, 1),
+            Directions::SW => (1, -1),
+        }
+    }
+
+    /// The index one step from `index` in `direction`, or `None` if that
+    /// leaves the grid, including stepping past either end of a row.
+    fn neighbour(&self, direction: &Directions, index: usize) -> Option<usize> {
+        let (dr, dc) = self.direction_step(direction);
+        let rows = self.data.len() / self.cols;
+        let row = (index / self.cols).checked_add_signed(dr).filter(|&r| r < rows)?;
+        let col = (index % self.cols)
+            .checked_add_signed(dc)
+            .filter(|&c| c < self.cols)?;
+        Some(row * self.cols + col)
+    }
+
+    fn check_letter(&self, direction: &Directions, index: usize, letter: char) -> Option<usize> {
+        self.neighbour(direction, index)
+            .filter(|&next_index| self.data[next_index] == letter)
+    }
+
+    pub fn check_xmas(&self, direction: &Directions, start: usize) -> bool {
+        let mut index = start;
+        for &letter in &['M', 'A', 'S'] {
+            if let Some(next_index) = self.check_letter(direction, index, letter) {
+                index = next_index;
+            } else {
+                return false;
+            }
+        }
+        true
+    }
+
+    pub fn check_x_mas(&self, start: usize) -> bool {
+        let indices: Option<Vec<_>> = [
+            Directions::NE,
+            Directions::NW,
+            Directions::SE,
+            Directions::SW,
+        ]
+        .iter()
+        .map(|direction| self.neighbour(direction, start))
+        .collect();
+
+        let Some(indices) = indices else {
+            return false;
+        };
+
+        let letters: Vec<_> = indices.iter().map(|&i| self.data[i]).collect();
+        let m_count = letters.iter().filter(|&&c| c == 'M').count();
+        let s_count = letters.iter().filter(|&&c| c == 'S').count();
+
+        m_count == 2 && s_count == 2 && letters[0] != letters[3] && letters[1] != letters[2]
+    }
+}
+
+pub struct Day04;
+
+impl Day for Day04 {
+    const YEAR: u16 = 2024;
+    const NUMBER: u8 = 4;
+    const TITLE: &'static str = "Ceres Search";
+
+    type Input = Grid;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Grid::new(input)
+    }
+
+    fn part1(grid: &Self::Input) -> Result<Solution, AocError> {
+        let mut total_xmas = 0;
+        for (i, &c) in grid.data.iter().enumerate() {
+            if c == 'X' {
+                for direction in Directions::all() {
+                    if grid.check_xmas(direction, i) {
+                        total_xmas += 1;
+                    }
+                }
+            }
+        }
+
+        Ok(Solution::from(total_xmas))
+    }
+
+    fn part2(grid: &Self::Input) -> Result<Solution, AocError> {
+        let mut total_x_mas = 0;
+        for (i, &c) in grid.data.iter().enumerate() {
+            if c == 'A' && grid.check_x_mas(i) {
+                total_x_mas += 1;
+            }
+        }
+
+        Ok(Solution::from(total_x_mas))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_x_mas_does_not_wrap_rows() {
+        // The `A` sits on the left edge; its NW and SW neighbours would be the
+        // ends of the rows above and below if steps wrapped around.
+        let grid = Day04::parse("..M\n.M.\nA.S\n.S.").unwrap();
+        assert_eq!(Day04::part2(&grid).unwrap(), Solution::from(0));
+    }
+
+    #[test]
+    fn test_xmas_on_narrow_grids() {
+        let grid = Day04::parse("X\nM\nA\nS").unwrap();
+        assert_eq!(Day04::part1(&grid).unwrap(), Solution::from(1));
+        let grid = Day04::parse("XM\nAS").unwrap();
+        assert_eq!(Day04::part1(&grid).unwrap(), Solution::from(0));
+    }
+}
@@ -0,0 +1,200 @@
+use tracing::{debug, info};
+
+use crate::etc::parse::number;
+use crate::etc::{AocError, Day};
+use crate::Solution;
+use std::collections::HashMap;
+
+#[derive(Debug)]
+pub struct RuleMap {
+    rules: HashMap<usize, Vec<usize>>,
+}
+
+impl RuleMap {
+    fn new(rule_str: &str) -> Result<Self, AocError> {
+        let mut rm = RuleMap {
+            rules: HashMap::new(),
+        };
+        for (n, r) in rule_str.lines().enumerate() {
+            // debug!("Rule {}", r);
+            let line = n + 1;
+            let (a, b) = r
+                .split_once("|")
+                .ok_or_else(|| AocError::parse(line, 1, "expected a rule like `47|53`"))?;
+            let ia = number::<usize>(a, line, 1)?;
+            let ib = number::<usize>(b, line, a.len() + 2)?;
+
+            let entry_ = rm.rules.entry(ia).or_insert(vec![]);
+            entry_.push(ib);
+        }
+        Ok(rm)
+    }
+}
+
+fn check_manual(manual: &[usize], rulemap: &RuleMap) -> bool {
+    let mut page_inserts = HashMap::new();
+    for (counter, p) in manual.iter().enumerate() {
+        page_inserts.insert(p, counter);
+        // debug!("Page {} {}", p, counter);
+        if let Some(rules_for_page) = rulemap.rules.get(p) {
+            // debug!("Rules exist for page {} {:?}", p, rules_for_page);
+            for r in rules_for_page {
+                // debug!("Checking if page {} present", r);
+                if let Some(pi) = page_inserts.get(r) {
+                    // debug!("page {} present with value {} {}", r, pi, &counter > pi);
+                    if &counter > pi {
+                        return false;
+                    }
+                }
+            }
+        };
+    }
+    true
+}
+
+fn middle_value(manual: &[usize]) -> usize {
+    debug!("{} {}", ((manual.len() - 1) / 2), manual.len());
+    manual[(manual.len() - 1) / 2]
+}
+
+pub struct Day05;
+
+impl Day for Day05 {
+    const YEAR: u16 = 2024;
+    const NUMBER: u8 = 5;
+    const TITLE: &'static str = "Print Queue";
+
+    type Input = (RuleMap, Vec<Vec<usize>>);
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let (rules, pages) = input.split_once("\n\n").ok_or_else(|| {
+            AocError::parse(
+                input.lines().count() + 1,
+                1,
+                "expected a blank line between rules and updates",
+            )
+        })?;
+        debug!("rules {:?} pages {}", rules, pages);
+        let rulemap = RuleMap::new(rules)?;
+        debug!("rulemap {:?}", rulemap);
+
+        let first_page_line = rules.lines().count() + 2;
+        let mut manuals = vec![];
+        for (n, manual) in pages.lines().enumerate() {
+            if manual.trim().is_empty() {
+                continue;
+            }
+            let line = first_page_line + n;
+            let mut column = 1;
+            let mut vecman = vec![];
+            for x in manual.split(",") {
+                vecman.push(number::<usize>(x, line, column)?);
+                column += x.len() + 1;
+            }
+            manuals.push(vecman);
+        }
+        Ok((rulemap, manuals))
+    }
+
+    fn part1((rulemap, manuals): &Self::Input) -> Result<Solution, AocError> {
+        let mut total = 0;
+        for (i, manual) in manuals.iter().enumerate() {
+            if check_manual(manual, rulemap) {
+                info!("Valid {} {:?}", i, manual);
+                total += middle_value(manual);
+            }
+        }
+
+        Ok(Solution::from(total as u64))
+    }
+
+    fn part2((rulemap, manuals): &Self::Input) -> Result<Solution, AocError> {
+        let mut total2 = 0;
+        for (i, manual) in manuals.iter().enumerate() {
+            if !check_manual(manual, rulemap) {
+                info!("Invalid {} {:?}", i, manual);
+                let mv = fix_manual(manual, rulemap)?;
+                total2 += middle_value(&mv);
+            }
+        }
+
+        Ok(Solution::from(total2 as u64))
+    }
+}
+
+/// Reorders `manual` so every rule between its pages holds, taking the
+/// earliest page that no remaining page must precede each time. Fails if the
+/// rules between its pages form a cycle.
+fn fix_manual(manual: &[usize], rulemap: &RuleMap) -> Result<Vec<usize>, AocError> {
+    let mut remaining = manual.to_vec();
+    let mut fixed = Vec::with_capacity(manual.len());
+    while !remaining.is_empty() {
+        let must_wait = |p: &usize| {
+            remaining
+                .iter()
+                .any(|q| rulemap.rules.get(q).is_some_and(|after| after.contains(p)))
+        };
+        let Some(next) = remaining.iter().position(|p| !must_wait(p)) else {
+            return Err(AocError::InvalidInput(format!(
+                "the rules for update {:?} form a cycle",
+                manual
+            )));
+        };
+        fixed.push(remaining.remove(next));
+    }
+    debug!("Fixed {:?} -> {:?}", manual, fixed);
+    Ok(fixed)
+}
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+
+    #[test]
+    fn test_add() {
+        tracing_subscriber::fmt::init();
+        let manual = "97,13,75,29,47";
+        let rulesstr = "47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13";
+        let rulemap = RuleMap::new(rulesstr).unwrap();
+        debug!("Testing {}", manual);
+        let vecman: Vec<usize> = manual
+            .split(",")
+            .map(|x| x.parse::<usize>().unwrap())
+            .collect();
+        assert!(!check_manual(&vecman, &rulemap));
+        let fixed = fix_manual(&vecman, &rulemap).unwrap();
+        debug!("Fixed {} -> {:?}", manual, fixed);
+        assert_eq!(fixed, vec![97, 75, 47, 29, 13]);
+        assert!(check_manual(&fixed, &rulemap));
+    }
+
+    #[test]
+    fn test_cyclic_rules() {
+        let input = Day05::parse("1|2\n2|1\n\n1,2\n").unwrap();
+        assert!(matches!(
+            Day05::part2(&input),
+            Err(AocError::InvalidInput(_))
+        ));
+    }
+}
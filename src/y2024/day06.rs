@@ -1,14 +1,14 @@
 use tracing::{debug, info};
 
-use crate::{Solution, SolutionPair};
-use std::{
-    collections::{HashMap, HashSet},
-    fs::read_to_string,
-};
+use crate::etc::grid::parse_char_grid;
+use crate::etc::{AocError, Day};
+use crate::Solution;
+use std::collections::{HashMap, HashSet};
 
 ///////////////////////////////////////////////////////////////////////////////
 
-struct Grid {
+#[derive(Clone)]
+pub struct Grid {
     cols: usize,
     rows: usize,
     data: Vec<char>,
@@ -18,7 +18,7 @@ struct Grid {
 }
 
 #[derive(Debug)]
-enum Directions {
+pub enum Directions {
     N,
     S,
     E,
@@ -31,9 +31,7 @@ fn rotate(c: char) -> Option<char> {
         '>' => Some('v'),
         'v' => Some('<'),
         '<' => Some('^'),
-        _ => {
-            return None;
-        }
+        _ => None,
     }
 }
 impl Grid {
@@ -49,14 +47,19 @@ impl Grid {
         }
         outstr
     }
-    pub fn new(input: &str) -> Self {
-        let cols = input.lines().next().unwrap_or("").len();
-        let rows = input.lines().count();
-        let data: Vec<char> = input.chars().filter(|&c| c != '\n').collect();
+    pub fn new(input: &str) -> Result<Self, AocError> {
+        let (cols, rows, data) = parse_char_grid(input)?;
+        if let Some(i) = data.iter().position(|c| !".#^v<>".contains(*c)) {
+            return Err(AocError::parse(
+                i / cols + 1,
+                i % cols + 1,
+                format!("unexpected `{}` in map", data[i]),
+            ));
+        }
         let guard_pos = data
             .iter()
-            .position(|c| vec!['^', 'v', '>', '<'].contains(&c))
-            .unwrap();
+            .position(|c| ['^', 'v', '>', '<'].contains(c))
+            .ok_or_else(|| AocError::InvalidGrid("no guard found".to_owned()))?;
         let guard_char = data[guard_pos];
 
         let g = Self {
@@ -76,7 +79,7 @@ impl Grid {
             g.linear_to_coord(guard_pos)
         );
 
-        g
+        Ok(g)
     }
     pub fn move_guard(&mut self) -> Option<usize> {
         if self
@@ -163,10 +166,6 @@ impl Grid {
     fn is_in_bounds_vec(&self, x: i32, y: i32) -> bool {
         x >= 0 && x < self.cols as i32 && y >= 0 && y < self.rows as i32
     }
-    pub fn coord_to_linear(&self, x: usize, y: usize) -> usize {
-        // 1,1 should return 4, if width =3
-        (y * self.cols) + x
-    }
     pub fn linear_to_coord(&self, i: usize) -> (usize, usize) {
         // 1,1 should return 4, if width =3
         let x = i % self.cols;
@@ -175,46 +174,69 @@ impl Grid {
     }
 }
 
-pub fn solve() -> SolutionPair {
-    // While in bounds, move
-    let content = read_to_string("./input/day6.txt").unwrap_or_default();
-    let mut grid = Grid::new(&content);
+/// Walks the guard until it leaves the grid, returning the walked grid.
+fn patrol(grid: &Grid) -> Grid {
+    let mut grid = grid.clone();
     loop {
         let n = grid.move_guard();
         if n.is_none() {
             break;
         }
     }
-    let p1 = grid.visited.keys().count() as u64;
-    let mut total = 0;
-    debug!("PART 2");
-    let mut ctr = 0;
-    for p in grid.visited.keys() {
-        ctr += 1;
-        let mut grid2 = Grid::new(&content);
-        grid2.data[*p] = 'O';
-        info!("Trying pos {}/{}", ctr, p1);
-
-        loop {
-            let n = grid2.move_guard();
-            if n.is_some_and(|x| x == 2) {
-                debug!(
-                    "Found position creating loop! {:?} {}",
-                    grid2.linear_to_coord(*p),
-                    p
-                );
-                debug!("\n{}\n", grid2.show());
-                total += 1;
-                break;
-            };
-            if n.is_none() {
-                break;
-            };
-        }
+    grid
+}
+
+pub struct Day06;
+
+impl Day for Day06 {
+    const YEAR: u16 = 2024;
+    const NUMBER: u8 = 6;
+    const TITLE: &'static str = "Guard Gallivant";
+
+    type Input = Grid;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Grid::new(input)
     }
 
-    let sol1: u64 = grid.visited.keys().count() as u64;
-    let sol2: u64 = total;
+    fn part1(grid: &Self::Input) -> Result<Solution, AocError> {
+        let walked = patrol(grid);
+        let sol1: u64 = walked.visited.keys().count() as u64;
+
+        Ok(Solution::from(sol1))
+    }
 
-    (Solution::from(sol1), Solution::from(sol2))
+    fn part2(grid: &Self::Input) -> Result<Solution, AocError> {
+        // While in bounds, move
+        let walked = patrol(grid);
+        let p1 = walked.visited.keys().count();
+        let mut total: u64 = 0;
+        debug!("PART 2");
+        let mut ctr = 0;
+        for p in walked.visited.keys() {
+            ctr += 1;
+            let mut grid2 = grid.clone();
+            grid2.data[*p] = 'O';
+            info!("Trying pos {}/{}", ctr, p1);
+
+            loop {
+                let n = grid2.move_guard();
+                if n.is_some_and(|x| x == 2) {
+                    debug!(
+                        "Found position creating loop! {:?} {}",
+                        grid2.linear_to_coord(*p),
+                        p
+                    );
+                    debug!("\n{}\n", grid2.show());
+                    total += 1;
+                    break;
+                };
+                if n.is_none() {
+                    break;
+                };
+            }
+        }
+
+        Ok(Solution::from(total))
+    }
 }
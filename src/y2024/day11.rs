@@ -0,0 +1,28 @@
+use crate::etc::{AocError, Day};
+use crate::Solution;
+
+///////////////////////////////////////////////////////////////////////////////
+
+pub struct Day11;
+
+impl Day for Day11 {
+    const YEAR: u16 = 2024;
+    const NUMBER: u8 = 11;
+    const TITLE: &'static str = "Plutonian Pebbles";
+    const IMPLEMENTED: bool = false;
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Ok(input.to_owned())
+    }
+
+    fn part1(_input: &Self::Input) -> Result<Solution, AocError> {
+        // Your solution here...
+        Ok(Solution::Unsolved)
+    }
+
+    fn part2(_input: &Self::Input) -> Result<Solution, AocError> {
+        Ok(Solution::Unsolved)
+    }
+}
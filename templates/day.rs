@@ -0,0 +1,30 @@
+use crate::etc::{AocError, Day};
+use crate::Solution;
+
+///////////////////////////////////////////////////////////////////////////////
+
+pub struct Day{{NN}};
+
+impl Day for Day{{NN}} {
+    const YEAR: u16 = {{YEAR}};
+    const NUMBER: u8 = {{N}};
+    const TITLE: &'static str = "{{TITLE}}";
+    // Remove once the day has a real solution.
+    const IMPLEMENTED: bool = false;
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Ok(input.lines().map(str::to_owned).collect())
+    }
+
+    fn part1(_input: &Self::Input) -> Result<Solution, AocError> {
+        // Your solution here...
+        Ok(Solution::Unsolved)
+    }
+
+    fn part2(_input: &Self::Input) -> Result<Solution, AocError> {
+        Ok(Solution::Unsolved)
+    }
+}
+
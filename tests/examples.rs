@@ -0,0 +1,32 @@
+//! Runs every day against its puzzle examples. The tests are generated by
+//! `build.rs` from `input/YYYY/dayN_short*.answers`; see the README.
+
+use advent_of_code_template::{days, Answers, Part, Verdict};
+use std::fs;
+use std::path::Path;
+
+fn check_example(year: u16, day: u8, name: &str, part: Part) {
+    // `build.rs` resolves the input directory from `aoc.toml`.
+    let dir = Path::new(env!("AOC_INPUT_DIR")).join(year.to_string());
+    let read = |file: String| {
+        fs::read_to_string(dir.join(&file)).unwrap_or_else(|e| panic!("{}: {}", file, e))
+    };
+    let input = read(format!("{}.txt", name));
+    let answers = Answers::parse(&read(format!("{}.answers", name))).unwrap();
+
+    let solver =
+        days::get(year, day).unwrap_or_else(|| panic!("{} day {} is not registered", year, day));
+    let parsed = solver.parse(&input).unwrap();
+    let answer = solver.solve(part, &*parsed).unwrap();
+    match answers.check(part, &answer) {
+        Verdict::Pass => {}
+        Verdict::Fail { expected } => panic!(
+            "{} part {}: expected {}, got {}",
+            name, part, expected, answer
+        ),
+        Verdict::Unknown => panic!("{} has no answer for part {}", name, part),
+        Verdict::Unsolved => panic!("{} part {} is not solved yet", name, part),
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
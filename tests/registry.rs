@@ -0,0 +1,28 @@
+use advent_of_code_template::days;
+use advent_of_code_template::y2024::day01::Day01;
+use advent_of_code_template::{Day, Part};
+
+#[test]
+fn test_registry_is_ordered() {
+    let keys: Vec<(u16, u8)> = days::all().iter().map(|s| (s.year(), s.number())).collect();
+    let mut sorted = keys.clone();
+    sorted.sort();
+    sorted.dedup();
+    assert_eq!(keys, sorted);
+
+    let numbers: Vec<u8> = days::of_year(2024).map(|s| s.number()).collect();
+    assert_eq!(numbers, (1..=25).collect::<Vec<_>>());
+    assert_eq!(days::get(2024, 5).map(|s| s.number()), Some(5));
+    assert!(days::years().contains(&2024));
+}
+
+#[test]
+fn test_solve_through_library() {
+    let example = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+    let input = Day01::parse(example).unwrap();
+    assert_eq!(Day01::part1(&input).unwrap().to_string(), "11");
+
+    let solver = days::get(2024, 1).unwrap();
+    let input = solver.parse(example).unwrap();
+    assert_eq!(solver.solve(Part::Two, &*input).unwrap().to_string(), "31");
+}